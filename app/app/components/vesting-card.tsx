@@ -2,18 +2,14 @@
 
 import { useState } from "react";
 import { PublicKey } from "@solana/web3.js";
-import { calculateReleased } from "../lib/program";
+import { calculateReleased, VestingSchedule } from "../lib/program";
 import { getTokenSymbol } from "../lib/utils/token-symbols";
 
-interface VestingData {
+interface VestingData extends VestingSchedule {
   admin: PublicKey;
   beneficiary: PublicKey;
   mint: PublicKey;
-  totalAmount: { toNumber: () => number };
   releasedAmount: { toNumber: () => number };
-  startTime: { toNumber: () => number };
-  cliffTime: { toNumber: () => number };
-  endTime: { toNumber: () => number };
   seed: { toNumber: () => number };
   bump: number;
   vaultBalance?: number;
@@ -102,13 +98,7 @@ export function VestingCard({
   const status = getStatus(account, now);
   const divisor = 10 ** decimals;
 
-  const currentReleased = calculateReleased(
-    total,
-    account.startTime.toNumber(),
-    account.cliffTime.toNumber(),
-    account.endTime.toNumber(),
-    now
-  );
+  const currentReleased = calculateReleased(account, now);
   const claimable = Math.max(0, currentReleased - released);
   const progress = total > 0 ? (currentReleased / total) * 100 : 0;
   
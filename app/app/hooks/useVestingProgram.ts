@@ -151,12 +151,26 @@ export function useVestingProgram() {
     }) => {
       if (!wallet.publicKey) throw new Error("Wallet not connected");
 
-      const [vestingPda] = getVestingPda(beneficiary, mint, seed);
+      const [vestingPda] = getVestingPda(wallet.publicKey, beneficiary, mint, seed);
       const vault = await getAssociatedTokenAddress(mint, vestingPda, true);
 
       try {
         const tx = await program.methods
-          .createVesting(seed, totalAmount, startTime, cliffTime, endTime)
+          .createVesting({
+            seed,
+            totalAmount,
+            startTime,
+            cliffTime,
+            endTime,
+            revocable: false,
+            scheduleKind: { linear: {} },
+            periodSeconds: new BN(0),
+            schedulePoints: [],
+            cliffAmount: new BN(0),
+            accrueFromCliff: false,
+            allowPermissionlessClaim: false,
+            transferable: false,
+          })
           .accountsPartial({
             admin: wallet.publicKey,
             beneficiary,
@@ -261,17 +275,18 @@ export function useVestingProgram() {
     }) => {
       if (!wallet.publicKey) throw new Error("Wallet not connected");
 
+      // The connected wallet may be the beneficiary or its claim delegate;
+      // tokens always go to the beneficiary's ATA
+      const { beneficiary } = await program.account.vestingAccount.fetch(vestingPda);
       const vault = await getAssociatedTokenAddress(mint, vestingPda, true);
-      const beneficiaryAta = await getAssociatedTokenAddress(
-        mint,
-        wallet.publicKey
-      );
+      const beneficiaryAta = await getAssociatedTokenAddress(mint, beneficiary);
 
       try {
         const tx = await program.methods
-          .claim()
+          .claim(null)
           .accountsPartial({
-            beneficiary: wallet.publicKey,
+            claimer: wallet.publicKey,
+            beneficiary,
             mint,
             vestingAccount: vestingPda,
             vault,
@@ -1,4 +1,4 @@
-import { Program, AnchorProvider } from "@coral-xyz/anchor";
+import { Program, AnchorProvider, IdlAccounts } from "@coral-xyz/anchor";
 import { PublicKey, Connection } from "@solana/web3.js";
 import { AnchorVesting } from "./types";
 import IDL from "./idl.json";
@@ -6,11 +6,31 @@ import BN from "bn.js";
 
 export const PROGRAM_ID = new PublicKey(IDL.address);
 
+export type VestingAccount = IdlAccounts<AnchorVesting>["vestingAccount"];
+
+/** Fields of a vesting account that determine its release schedule */
+export type VestingSchedule = Pick<
+  VestingAccount,
+  | "totalAmount"
+  | "startTime"
+  | "cliffTime"
+  | "endTime"
+  | "revokedAt"
+  | "status"
+  | "scheduleKind"
+  | "periodSeconds"
+  | "scheduleLen"
+  | "schedulePoints"
+  | "cliffAmount"
+  | "accrueFromCliff"
+>;
+
 export function getProgram(provider: AnchorProvider): Program<AnchorVesting> {
   return new Program(IDL as AnchorVesting, provider);
 }
 
 export function getVestingPda(
+  admin: PublicKey,
   beneficiary: PublicKey,
   mint: PublicKey,
   seed: BN
@@ -18,6 +38,7 @@ export function getVestingPda(
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from("vesting"),
+      admin.toBuffer(),
       beneficiary.toBuffer(),
       mint.toBuffer(),
       seed.toArrayLike(Buffer, "le", 8),
@@ -26,17 +47,47 @@ export function getVestingPda(
   );
 }
 
-export function calculateReleased(
-  totalAmount: number,
-  startTime: number,
-  cliffTime: number,
-  endTime: number,
-  now: number
-): number {
-  if (now < cliffTime) return 0;
-  if (now >= endTime) return totalAmount;
-
-  const elapsed = now - startTime;
-  const duration = endTime - startTime;
-  return Math.floor((totalAmount * elapsed) / duration);
+/** Mirrors `VestingAccount::calculate_released` in the program */
+export function calculateReleased(account: VestingSchedule, now: number): number {
+  // Revoked: the schedule is frozen at the revocation time
+  if ("revoked" in account.status) {
+    now = Math.min(now, account.revokedAt.toNumber());
+  }
+
+  if (now < account.cliffTime.toNumber()) return 0;
+  if (now >= account.endTime.toNumber()) return account.totalAmount.toNumber();
+
+  // cliffAmount unlocks at the cliff; the rest accrues from the accrual start
+  const accrualStart = account.accrueFromCliff ? account.cliffTime : account.startTime;
+  const elapsed = new BN(now).sub(accrualStart);
+  const duration = account.endTime.sub(accrualStart);
+  const accruing = account.totalAmount.sub(account.cliffAmount);
+
+  if ("linear" in account.scheduleKind) {
+    return account.cliffAmount.add(accruing.mul(elapsed).div(duration)).toNumber();
+  }
+  if ("periodic" in account.scheduleKind) {
+    const period = account.periodSeconds;
+    const periods = duration.add(period).subn(1).div(period);
+    return account.cliffAmount
+      .add(accruing.div(periods).mul(elapsed.div(period)))
+      .toNumber();
+  }
+
+  // Custom schedules: last point reached by `now`
+  const points = account.schedulePoints.slice(0, account.scheduleLen);
+  let i = points.length - 1;
+  while (i >= 0 && points[i].timestamp.toNumber() > now) i--;
+  if (i < 0) return 0;
+
+  const current = points[i];
+  const next = points[i + 1];
+  if ("customLinear" in account.scheduleKind && next) {
+    const span = next.timestamp.sub(current.timestamp);
+    const step = next.amount.sub(current.amount);
+    return current.amount
+      .add(step.mul(new BN(now).sub(current.timestamp)).div(span))
+      .toNumber();
+  }
+  return current.amount.toNumber();
 }
@@ -13,6 +13,48 @@ export type AnchorVesting = {
     "description": "Secure Token Vesting & Escrow Program"
   },
   "instructions": [
+    {
+      "name": "acceptAdmin",
+      "discriminator": [
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "newAdmin",
+          "signer": true
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
     {
       "name": "claim",
       "discriminator": [
@@ -27,11 +69,17 @@ export type AnchorVesting = {
       ],
       "accounts": [
         {
-          "name": "beneficiary",
+          "name": "claimer",
+          "docs": [
+            "The beneficiary or its registered claim delegate, or the receipt holder"
+          ],
           "writable": true,
-          "signer": true,
-          "relations": [
-            "vestingAccount"
+          "signer": true
+        },
+        {
+          "name": "beneficiary",
+          "docs": [
+            "receipt holder in the handler when the vesting has a receipt"
           ]
         },
         {
@@ -168,74 +216,172 @@ export type AnchorVesting = {
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "receiptTokenAccount",
+          "docs": [
+            "The claimer's receipt token account; required when the vesting has a receipt"
+          ],
+          "optional": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
     },
     {
-      "name": "createVesting",
+      "name": "claimMerkle",
       "discriminator": [
-        135,
-        184,
-        171,
-        156,
-        197,
-        162,
-        246,
-        44
+        16,
+        148,
+        142,
+        174,
+        132,
+        79,
+        20,
+        148
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "beneficiary",
           "writable": true,
           "signer": true
         },
         {
-          "name": "beneficiary"
+          "name": "mint",
+          "relations": [
+            "merkleVesting"
+          ]
         },
         {
-          "name": "mint"
+          "name": "merkleVesting",
+          "writable": true
         },
         {
-          "name": "vestingAccount",
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "merkleVesting"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "claimStatus",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  101,
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  95,
                   115,
                   116,
-                  105,
-                  110,
-                  103
+                  97,
+                  116,
+                  117,
+                  115
                 ]
               },
               {
                 "kind": "account",
-                "path": "beneficiary"
+                "path": "merkleVesting"
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "beneficiary"
               },
               {
                 "kind": "arg",
-                "path": "seed"
+                "path": "leaf"
               }
             ]
           }
         },
         {
-          "name": "vault",
+          "name": "beneficiaryTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "vestingAccount"
+                "path": "beneficiary"
               },
               {
                 "kind": "account",
@@ -295,47 +441,69 @@ export type AnchorVesting = {
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         }
       ],
       "args": [
         {
-          "name": "seed",
-          "type": "u64"
-        },
-        {
-          "name": "totalAmount",
-          "type": "u64"
-        },
-        {
-          "name": "startTime",
-          "type": "i64"
+          "name": "leaf",
+          "type": {
+            "defined": {
+              "name": "merkleLeaf"
+            }
+          }
         },
         {
-          "name": "cliffTime",
-          "type": "i64"
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
         },
         {
-          "name": "endTime",
-          "type": "i64"
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
         }
       ]
     },
     {
-      "name": "deposit",
+      "name": "claimTo",
       "discriminator": [
-        242,
-        35,
-        198,
-        137,
-        82,
+        111,
+        34,
         225,
+        195,
+        66,
+        4,
         242,
-        182
+        165
       ],
       "accounts": [
         {
-          "name": "admin",
-          "writable": true,
+          "name": "beneficiary",
           "signer": true,
           "relations": [
             "vestingAccount"
@@ -409,31 +577,352 @@ export type AnchorVesting = {
           }
         },
         {
-          "name": "adminTokenAccount",
+          "name": "destination",
+          "docs": [
+            "Any token account for the vesting mint (multisig vault, exchange deposit, ...)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeClaimStatus",
+      "discriminator": [
+        163,
+        214,
+        191,
+        165,
+        245,
+        188,
+        17,
+        185
+      ],
+      "accounts": [
+        {
+          "name": "beneficiary",
+          "docs": [
+            "Paid the claim status rent on the first claim and gets it back"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "merkleVesting",
+          "docs": [
+            "fresh claim status could replay the leaf's claims"
+          ]
+        },
+        {
+          "name": "claimStatus",
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  117,
+                  115
+                ]
+              },
               {
                 "kind": "account",
-                "path": "admin"
+                "path": "merkleVesting"
               },
               {
                 "kind": "account",
-                "path": "tokenProgram"
+                "path": "beneficiary"
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "claim_status.leaf_hash",
+                "account": "claimStatus"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeMerkleVesting",
+      "discriminator": [
+        77,
+        102,
+        4,
+        182,
+        237,
+        136,
+        127,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Receives the unclaimed tokens and the rent of the distributor and its vault"
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "merkleVesting"
+          ]
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "merkleVesting"
+          ]
+        },
+        {
+          "name": "merkleVesting",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "merkleVesting"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeVesting",
+      "discriminator": [
+        231,
+        75,
+        231,
+        133,
+        93,
+        227,
+        250,
+        252
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "The current admin; receives the rent of the vesting account and the vault.",
+            "After `accept_admin` that is the new admin, not whoever paid it at creation."
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vestingAccount"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
                 36,
                 137,
                 241,
@@ -464,160 +953,3547 @@ export type AnchorVesting = {
               ]
             }
           }
-        },
-        {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "vestingAccount",
-      "discriminator": [
-        102,
-        73,
-        10,
-        233,
-        200,
-        188,
-        228,
-        216
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "invalidTimeRange",
-      "msg": "Invalid time range: must satisfy start <= cliff <= end and start < end"
-    },
-    {
-      "code": 6001,
-      "name": "invalidAmount",
-      "msg": "Invalid amount: total_amount must be greater than 0"
-    },
-    {
-      "code": 6002,
-      "name": "unauthorizedAdmin",
-      "msg": "Unauthorized: only admin can deposit"
-    },
-    {
-      "code": 6003,
-      "name": "unauthorizedBeneficiary",
-      "msg": "Unauthorized: only beneficiary can claim"
-    },
-    {
-      "code": 6004,
-      "name": "alreadyFunded",
-      "msg": "Already funded: vault already contains tokens"
-    },
-    {
-      "code": 6005,
-      "name": "notFunded",
-      "msg": "Not funded: must deposit before claiming"
-    },
-    {
-      "code": 6006,
-      "name": "nothingToClaim",
-      "msg": "Nothing to claim: no tokens available for release"
-    },
-    {
-      "code": 6007,
-      "name": "mintMismatch",
-      "msg": "Mint mismatch: deposited token mint does not match vesting"
+        },
+        {
+          "name": "adminTokenAccount",
+          "docs": [
+            "Receives any surplus tokens sent straight to the vault"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "receiptMint",
+          "docs": [
+            "The receipt NFT mint; required when the vesting has a receipt"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptTokenAccount",
+          "docs": [
+            "Whichever token account currently holds the receipt NFT"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "token2022Program",
+          "optional": true,
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "crankClaim",
+      "discriminator": [
+        193,
+        62,
+        163,
+        14,
+        168,
+        236,
+        179,
+        103
+      ],
+      "accounts": [
+        {
+          "name": "cranker",
+          "docs": [
+            "Any keeper; pays fees and, if needed, the beneficiary ATA rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "beneficiary",
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vestingAccount"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "beneficiaryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "beneficiary"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createAndFundVesting",
+      "discriminator": [
+        33,
+        14,
+        36,
+        43,
+        74,
+        29,
+        242,
+        163
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "beneficiary"
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "account",
+                "path": "beneficiary"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "arg",
+                "path": "params.seed"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "`init_if_needed`: anyone can create the ATA first, which must not block the grant"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vestingAccount"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "docs": [
+            "Deposit fee recipient; only required when a deposit fee is charged"
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "createVestingParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createMerkleVesting",
+      "discriminator": [
+        195,
+        163,
+        145,
+        254,
+        71,
+        58,
+        9,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "merkleVesting",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  114,
+                  107,
+                  108,
+                  101,
+                  95,
+                  118,
+                  101,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "arg",
+                "path": "seed"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "`init_if_needed`: anyone can create the ATA first, which must not block the grant"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "merkleVesting"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "docs": [
+            "Deposit fee recipient; only required when a deposit fee is charged"
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "seed",
+          "type": "u64"
+        },
+        {
+          "name": "merkleRoot",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "totalAmount",
+          "type": "u64"
+        },
+        {
+          "name": "clawbackTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "createVesting",
+      "discriminator": [
+        135,
+        184,
+        171,
+        156,
+        197,
+        162,
+        246,
+        44
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "beneficiary"
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "account",
+                "path": "beneficiary"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "arg",
+                "path": "params.seed"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "`init_if_needed`: anyone can create the ATA first, which must not block the grant"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vestingAccount"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptMint",
+          "docs": [
+            "Pass to represent the position as a 1-of-1 Token-2022 NFT receipt"
+          ],
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "receiptTokenAccount",
+          "docs": [
+            "The beneficiary's receipt ATA, receiving the NFT"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "beneficiary"
+              },
+              {
+                "kind": "account",
+                "path": "token2022Program"
+              },
+              {
+                "kind": "account",
+                "path": "receiptMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "token2022Program",
+          "optional": true,
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "createVestingParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createVestingBatch",
+      "discriminator": [
+        36,
+        156,
+        31,
+        86,
+        206,
+        46,
+        198,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "adminTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "docs": [
+            "Deposit fee recipient; only required when a deposit fee is charged"
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "entries",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "batchVestingEntry"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "deposit",
+      "discriminator": [
+        242,
+        35,
+        198,
+        137,
+        82,
+        225,
+        242,
+        182
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vestingAccount"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "docs": [
+            "Deposit fee recipient; only required when a deposit fee is charged"
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeConfig",
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "BB1JtUxXtmDnb6L5qXUSfuvT18TggYuSLBzfmjoYFnb4"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        },
+        {
+          "name": "pauseFlags",
+          "type": "u8"
+        },
+        {
+          "name": "treasury",
+          "type": "pubkey"
+        },
+        {
+          "name": "creationFeeLamports",
+          "type": "u64"
+        },
+        {
+          "name": "depositFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "feeExempt",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateVesting",
+      "discriminator": [
+        229,
+        93,
+        67,
+        21,
+        45,
+        9,
+        107,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone may migrate; the payer tops up rent for the larger layout"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vestingAccount"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeAdmin",
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "revoke",
+      "discriminator": [
+        170,
+        23,
+        31,
+        34,
+        133,
+        173,
+        93,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vestingAccount"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "beneficiaryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "beneficiary"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setClaimDelegate",
+      "discriminator": [
+        227,
+        191,
+        177,
+        221,
+        167,
+        40,
+        47,
+        138
+      ],
+      "accounts": [
+        {
+          "name": "beneficiary",
+          "signer": true,
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "claimDelegate",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "transferBeneficiary",
+      "discriminator": [
+        43,
+        100,
+        158,
+        183,
+        38,
+        120,
+        169,
+        249
+      ],
+      "accounts": [
+        {
+          "name": "beneficiary",
+          "signer": true,
+          "relations": [
+            "vestingAccount"
+          ]
+        },
+        {
+          "name": "admin",
+          "docs": [
+            "Required unless the vesting was created `transferable`"
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "vestingAccount",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newBeneficiary",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "BB1JtUxXtmDnb6L5qXUSfuvT18TggYuSLBzfmjoYFnb4"
+        },
+        {
+          "name": "programData"
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        },
+        {
+          "name": "pauseFlags",
+          "type": "u8"
+        },
+        {
+          "name": "treasury",
+          "type": "pubkey"
+        },
+        {
+          "name": "creationFeeLamports",
+          "type": "u64"
+        },
+        {
+          "name": "depositFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "feeExempt",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "claimStatus",
+      "discriminator": [
+        22,
+        183,
+        249,
+        157,
+        247,
+        95,
+        150,
+        96
+      ]
+    },
+    {
+      "name": "config",
+      "discriminator": [
+        155,
+        12,
+        170,
+        224,
+        30,
+        250,
+        204,
+        130
+      ]
+    },
+    {
+      "name": "merkleVesting",
+      "discriminator": [
+        119,
+        140,
+        25,
+        6,
+        240,
+        199,
+        77,
+        218
+      ]
+    },
+    {
+      "name": "vestingAccount",
+      "discriminator": [
+        102,
+        73,
+        10,
+        233,
+        200,
+        188,
+        228,
+        216
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "adminProposed",
+      "discriminator": [
+        129,
+        249,
+        226,
+        227,
+        199,
+        82,
+        110,
+        243
+      ]
+    },
+    {
+      "name": "adminTransferred",
+      "discriminator": [
+        255,
+        147,
+        182,
+        5,
+        199,
+        217,
+        38,
+        179
+      ]
+    },
+    {
+      "name": "beneficiaryTransferred",
+      "discriminator": [
+        70,
+        88,
+        246,
+        222,
+        226,
+        28,
+        252,
+        39
+      ]
+    },
+    {
+      "name": "claimDelegateUpdated",
+      "discriminator": [
+        171,
+        91,
+        60,
+        247,
+        254,
+        62,
+        141,
+        226
+      ]
+    },
+    {
+      "name": "configUpdated",
+      "discriminator": [
+        40,
+        241,
+        230,
+        122,
+        11,
+        19,
+        198,
+        194
+      ]
+    },
+    {
+      "name": "merkleTokensClaimed",
+      "discriminator": [
+        191,
+        46,
+        231,
+        134,
+        199,
+        237,
+        81,
+        9
+      ]
+    },
+    {
+      "name": "merkleVestingClosed",
+      "discriminator": [
+        255,
+        183,
+        225,
+        57,
+        135,
+        227,
+        78,
+        138
+      ]
+    },
+    {
+      "name": "merkleVestingCreated",
+      "discriminator": [
+        91,
+        132,
+        46,
+        74,
+        226,
+        60,
+        128,
+        23
+      ]
+    },
+    {
+      "name": "receiptMinted",
+      "discriminator": [
+        100,
+        166,
+        3,
+        33,
+        2,
+        189,
+        140,
+        144
+      ]
+    },
+    {
+      "name": "tokensClaimed",
+      "discriminator": [
+        25,
+        128,
+        244,
+        55,
+        241,
+        136,
+        200,
+        91
+      ]
+    },
+    {
+      "name": "vestingClosed",
+      "discriminator": [
+        141,
+        146,
+        62,
+        52,
+        160,
+        60,
+        177,
+        123
+      ]
+    },
+    {
+      "name": "vestingCreated",
+      "discriminator": [
+        181,
+        223,
+        229,
+        220,
+        204,
+        6,
+        169,
+        125
+      ]
+    },
+    {
+      "name": "vestingFunded",
+      "discriminator": [
+        22,
+        228,
+        106,
+        171,
+        205,
+        204,
+        38,
+        238
+      ]
+    },
+    {
+      "name": "vestingRevoked",
+      "discriminator": [
+        215,
+        148,
+        193,
+        127,
+        237,
+        245,
+        90,
+        75
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidTimeRange",
+      "msg": "Invalid time range: must satisfy start <= cliff <= end and start < end"
+    },
+    {
+      "code": 6001,
+      "name": "invalidAmount",
+      "msg": "Invalid amount: total_amount must be greater than 0"
+    },
+    {
+      "code": 6002,
+      "name": "unauthorizedAdmin",
+      "msg": "Unauthorized: only the admin can perform this action"
+    },
+    {
+      "code": 6003,
+      "name": "unauthorizedBeneficiary",
+      "msg": "Unauthorized: only beneficiary or its claim delegate can claim"
+    },
+    {
+      "code": 6004,
+      "name": "alreadyFunded",
+      "msg": "Already funded: vault already contains tokens"
+    },
+    {
+      "code": 6005,
+      "name": "notFunded",
+      "msg": "Not funded: must deposit before claiming"
+    },
+    {
+      "code": 6006,
+      "name": "nothingToClaim",
+      "msg": "Nothing to claim: no tokens available for release"
+    },
+    {
+      "code": 6007,
+      "name": "mintMismatch",
+      "msg": "Mint mismatch: deposited token mint does not match vesting"
+    },
+    {
+      "code": 6008,
+      "name": "depositAmountMismatch",
+      "msg": "Deposit amount mismatch: must equal total_amount"
+    },
+    {
+      "code": 6009,
+      "name": "overflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6010,
+      "name": "notRevocable",
+      "msg": "Not revocable: this vesting was created as irrevocable"
+    },
+    {
+      "code": 6011,
+      "name": "alreadyRevoked",
+      "msg": "Already revoked: this vesting has been revoked"
+    },
+    {
+      "code": 6012,
+      "name": "vestingNotComplete",
+      "msg": "Vesting not complete: tokens remain to be released"
+    },
+    {
+      "code": 6013,
+      "name": "invalidStatus",
+      "msg": "Invalid status: instruction not allowed in the vesting's current status"
+    },
+    {
+      "code": 6014,
+      "name": "invalidLegacyAccount",
+      "msg": "Invalid legacy account: not a pre-upgrade vesting account"
+    },
+    {
+      "code": 6015,
+      "name": "invalidPeriod",
+      "msg": "Invalid period: periodic schedules need 0 < period_seconds <= end - accrual start (cliff if accrue_from_cliff, else start), linear ones 0"
+    },
+    {
+      "code": 6016,
+      "name": "invalidSchedule",
+      "msg": "Invalid schedule: points must be non-decreasing and end at (end_time, total_amount)"
+    },
+    {
+      "code": 6017,
+      "name": "invalidCliffAmount",
+      "msg": "Invalid cliff amount: must not exceed total_amount (and must be 0 for custom schedules)"
+    },
+    {
+      "code": 6018,
+      "name": "claimAmountExceedsClaimable",
+      "msg": "Claim amount exceeds the currently claimable balance"
+    },
+    {
+      "code": 6019,
+      "name": "permissionlessClaimDisabled",
+      "msg": "Permissionless claims are not enabled for this vesting"
+    },
+    {
+      "code": 6020,
+      "name": "notPendingAdmin",
+      "msg": "Signer is not the pending admin"
+    },
+    {
+      "code": 6021,
+      "name": "notTransferable",
+      "msg": "Vesting is not transferable without an admin co-signature"
+    },
+    {
+      "code": 6022,
+      "name": "programPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6023,
+      "name": "unauthorizedConfigAuthority",
+      "msg": "Unauthorized: only the program upgrade authority can manage config"
+    },
+    {
+      "code": 6024,
+      "name": "invalidFeeConfig",
+      "msg": "Invalid fee config: fee bps above 10000 or too many fee-exempt admins"
+    },
+    {
+      "code": 6025,
+      "name": "missingTreasuryAccount",
+      "msg": "Treasury account is required when a protocol fee is charged"
+    },
+    {
+      "code": 6026,
+      "name": "invalidBatchAccounts",
+      "msg": "Batch remaining accounts do not match the entries"
+    },
+    {
+      "code": 6027,
+      "name": "invalidMerkleProof",
+      "msg": "Invalid merkle proof, or leaf differs from the one proven on the first claim"
+    },
+    {
+      "code": 6028,
+      "name": "notAllowedWithReceipt",
+      "msg": "Not allowed for a vesting represented by an NFT receipt"
+    },
+    {
+      "code": 6029,
+      "name": "invalidReceipt",
+      "msg": "Invalid receipt: signer must hold this vesting's NFT receipt"
+    }
+  ],
+  "types": [
+    {
+      "name": "adminProposed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "Proposed admin, `Pubkey::default()` when the proposal is cancelled"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAdmin",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "batchVestingEntry",
+      "docs": [
+        "One vesting of a `create_vesting_batch` call; same parameters as `create_vesting`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "params",
+            "type": {
+              "defined": {
+                "name": "createVestingParams"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "beneficiaryTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousBeneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "newBeneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "adminCosigned",
+            "docs": [
+              "Whether the admin co-signed the transfer"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimDelegateUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "claimDelegate",
+            "docs": [
+              "New delegate, `Pubkey::default()` when cleared"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimStatus",
+      "docs": [
+        "Per-recipient claim progress, created on the recipient's first claim"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "leafHash",
+            "docs": [
+              "Hash of the proven leaf (also a PDA seed); zero until the first claim"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releasedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
+      "docs": [
+        "Program-wide switches and protocol fees, managed by the program upgrade authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paused",
+            "docs": [
+              "Emergency stop for every vesting instruction"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "pauseFlags",
+            "docs": [
+              "Finer-grained pauses (`PAUSE_CREATE`, `PAUSE_DEPOSIT`, `PAUSE_CLAIM`)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Receives the SOL creation fee and owns the token accounts the deposit fee goes to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "creationFeeLamports",
+            "docs": [
+              "Flat SOL fee charged on every vesting creation"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "depositFeeBps",
+            "docs": [
+              "Token fee charged on deposit, in basis points of total_amount, on top of it"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeExemptLen",
+            "type": "u8"
+          },
+          {
+            "name": "feeExempt",
+            "docs": [
+              "Partner admins that pay no fees; only the first `fee_exempt_len` entries are used"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "creationFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "depositFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "feeExemptLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "createVestingParams",
+      "docs": [
+        "Parameters of a single vesting, shared by `create_vesting`,",
+        "`create_and_fund_vesting` and each `create_vesting_batch` entry"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seed",
+            "type": "u64"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "cliffTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "revocable",
+            "type": "bool"
+          },
+          {
+            "name": "scheduleKind",
+            "type": {
+              "defined": {
+                "name": "scheduleKind"
+              }
+            }
+          },
+          {
+            "name": "periodSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "schedulePoints",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "schedulePoint"
+                }
+              }
+            }
+          },
+          {
+            "name": "cliffAmount",
+            "type": "u64"
+          },
+          {
+            "name": "accrueFromCliff",
+            "type": "bool"
+          },
+          {
+            "name": "allowPermissionlessClaim",
+            "type": "bool"
+          },
+          {
+            "name": "transferable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "merkleLeaf",
+      "docs": [
+        "A recipient's allocation and schedule, as committed to in the merkle tree"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "cliffTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "scheduleKind",
+            "type": {
+              "defined": {
+                "name": "scheduleKind"
+              }
+            }
+          },
+          {
+            "name": "periodSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "schedulePoints",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "schedulePoint"
+                }
+              }
+            }
+          },
+          {
+            "name": "cliffAmount",
+            "type": "u64"
+          },
+          {
+            "name": "accrueFromCliff",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "merkleTokensClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleVesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Tokens transferred to the beneficiary by this claim"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releasedAmount",
+            "docs": [
+              "The beneficiary's cumulative released_amount after the claim"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalAmount",
+            "docs": [
+              "The beneficiary's leaf total_amount"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "vaultBalance",
+            "docs": [
+              "Vault balance after the claim"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "merkleVesting",
+      "docs": [
+        "One vault shared by every recipient committed to in `merkle_root`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "merkleRoot",
+            "docs": [
+              "Root of the tree of `MerkleLeaf` hashes"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "seed",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalAmount",
+            "docs": [
+              "Sum of every leaf's total_amount, deposited at creation. The program",
+              "cannot check it against the leaves; the admin must pass the exact sum"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releasedAmount",
+            "docs": [
+              "Tokens claimed so far across all recipients"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "clawbackTime",
+            "docs": [
+              "From this time the admin may close the distributor and reclaim whatever",
+              "is unclaimed (0 = only once everything has been claimed)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "merkleVestingClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleVesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seed",
+            "type": "u64"
+          },
+          {
+            "name": "reclaimedAmount",
+            "docs": [
+              "Unclaimed (and surplus) tokens returned to the admin"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "merkleVestingCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleVesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seed",
+            "type": "u64"
+          },
+          {
+            "name": "merkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "totalAmount",
+            "docs": [
+              "Tokens deposited into the shared vault (sum of all leaves)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "creationFeeLamports",
+            "docs": [
+              "SOL creation fee paid to the treasury (0 when exempt)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "docs": [
+              "Protocol fee sent to the treasury on top of `total_amount` (0 when exempt)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "clawbackTime",
+            "docs": [
+              "When the admin may reclaim unclaimed tokens (0 = never)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "receiptMinted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "receiptMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "holder",
+            "docs": [
+              "Initial holder (the beneficiary)"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "scheduleKind",
+      "docs": [
+        "How tokens are released between the cliff and `end_time`"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "linear"
+          },
+          {
+            "name": "periodic"
+          },
+          {
+            "name": "customLinear"
+          },
+          {
+            "name": "customStep"
+          }
+        ]
+      }
+    },
+    {
+      "name": "schedulePoint",
+      "docs": [
+        "One point of a custom schedule: `amount` tokens released in total by `timestamp`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Cumulative amount released at `timestamp`"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokensClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seed",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Tokens transferred to the beneficiary by this claim"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releasedAmount",
+            "docs": [
+              "Cumulative released_amount after the claim"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "vaultBalance",
+            "docs": [
+              "Vault balance after the claim"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "The admin (Project Owner) who created this vesting"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "docs": [
+              "The beneficiary who can claim tokens"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The SPL Token mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalAmount",
+            "docs": [
+              "Total amount of tokens to be vested"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releasedAmount",
+            "docs": [
+              "Amount of tokens already released/claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "docs": [
+              "Vesting start time (unix timestamp)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cliffTime",
+            "docs": [
+              "Cliff end time (unix timestamp) - no tokens released before this"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "docs": [
+              "Vesting end time (unix timestamp) - all tokens released after this"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "seed",
+            "docs": [
+              "Unique seed to allow multiple vestings per beneficiary+mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "revocable",
+            "docs": [
+              "Whether the admin may revoke this vesting and reclaim unvested tokens"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "revokedAt",
+            "docs": [
+              "Revocation time (unix timestamp) - 0 while the vesting is active"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Funding lifecycle status (gates every instruction instead of vault balance)"
+            ],
+            "type": {
+              "defined": {
+                "name": "vestingStatus"
+              }
+            }
+          },
+          {
+            "name": "legacySeeds",
+            "docs": [
+              "Address derived without the admin key (migrated pre-upgrade account)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "scheduleKind",
+            "docs": [
+              "Release curve between the cliff and end_time"
+            ],
+            "type": {
+              "defined": {
+                "name": "scheduleKind"
+              }
+            }
+          },
+          {
+            "name": "periodSeconds",
+            "docs": [
+              "Step length in seconds for `Periodic` schedules (0 for `Linear`)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "scheduleLen",
+            "docs": [
+              "Number of used entries in `schedule_points` (custom schedules only)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "schedulePoints",
+            "docs": [
+              "Custom schedule table; entries past `schedule_len` are zeroed"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "schedulePoint"
+                  }
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "cliffAmount",
+            "docs": [
+              "Tokens unlocked at once when the cliff is reached (Linear/Periodic only)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "accrueFromCliff",
+            "docs": [
+              "Accrue the rest from cliff_time instead of start_time (Linear/Periodic only)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowPermissionlessClaim",
+            "docs": [
+              "Anyone may push claimable tokens to the beneficiary's ATA via `crank_claim`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "claimDelegate",
+            "docs": [
+              "May sign `claim` on the beneficiary's behalf (`Pubkey::default()` = none)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "Proposed new admin awaiting `accept_admin` (`Pubkey::default()` = none)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "seedAdmin",
+            "docs": [
+              "Admin the PDA was derived with; unchanged by admin transfers"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "transferable",
+            "docs": [
+              "Beneficiary may hand the grant to a new wallet without an admin co-signature"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "seedBeneficiary",
+            "docs": [
+              "Beneficiary the PDA was derived with; unchanged by beneficiary transfers"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "receiptMint",
+            "docs": [
+              "Token-2022 NFT whose holder claims instead of a fixed beneficiary (`Pubkey::default()` = none)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "layoutVersion",
+            "docs": [
+              "`VESTING_LAYOUT_VERSION` at creation or migration"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "depositAmountMismatch",
-      "msg": "Deposit amount mismatch: must equal total_amount"
+      "name": "vestingClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seed",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "overflow",
-      "msg": "Arithmetic overflow"
-    }
-  ],
-  "types": [
-    {
-      "name": "vestingAccount",
+      "name": "vestingCreated",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "vesting",
+            "type": "pubkey"
+          },
           {
             "name": "admin",
-            "docs": [
-              "The admin (Project Owner) who created this vesting"
-            ],
             "type": "pubkey"
           },
           {
             "name": "beneficiary",
-            "docs": [
-              "The beneficiary who can claim tokens"
-            ],
             "type": "pubkey"
           },
           {
             "name": "mint",
-            "docs": [
-              "The SPL Token mint"
-            ],
             "type": "pubkey"
           },
+          {
+            "name": "seed",
+            "type": "u64"
+          },
           {
             "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "cliffTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "revocable",
+            "type": "bool"
+          },
+          {
+            "name": "scheduleKind",
+            "type": {
+              "defined": {
+                "name": "scheduleKind"
+              }
+            }
+          },
+          {
+            "name": "periodSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "cliffAmount",
+            "type": "u64"
+          },
+          {
+            "name": "accrueFromCliff",
+            "type": "bool"
+          },
+          {
+            "name": "allowPermissionlessClaim",
+            "type": "bool"
+          },
+          {
+            "name": "transferable",
+            "type": "bool"
+          },
+          {
+            "name": "creationFeeLamports",
             "docs": [
-              "Total amount of tokens to be vested"
+              "SOL creation fee paid to the treasury (0 when exempt)"
             ],
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingFunded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vesting",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seed",
+            "type": "u64"
           },
           {
-            "name": "releasedAmount",
+            "name": "amount",
             "docs": [
-              "Amount of tokens already released/claimed"
+              "Tokens transferred into the vault by this deposit"
             ],
             "type": "u64"
           },
           {
-            "name": "startTime",
+            "name": "vaultBalance",
             "docs": [
-              "Vesting start time (unix timestamp)"
+              "Vault balance after the deposit"
             ],
-            "type": "i64"
+            "type": "u64"
           },
           {
-            "name": "cliffTime",
+            "name": "feeAmount",
             "docs": [
-              "Cliff end time (unix timestamp) - no tokens released before this"
+              "Protocol fee sent to the treasury on top of `amount` (0 when exempt)"
             ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
             "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingRevoked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vesting",
+            "type": "pubkey"
           },
           {
-            "name": "endTime",
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seed",
+            "type": "u64"
+          },
+          {
+            "name": "vestedAmount",
             "docs": [
-              "Vesting end time (unix timestamp) - all tokens released after this"
+              "Vested tokens paid out to the beneficiary on revocation"
             ],
-            "type": "i64"
+            "type": "u64"
           },
           {
-            "name": "seed",
+            "name": "returnedAmount",
             "docs": [
-              "Unique seed to allow multiple vestings per beneficiary+mint"
+              "Unvested tokens returned to the admin"
             ],
             "type": "u64"
           },
           {
-            "name": "bump",
+            "name": "releasedAmount",
             "docs": [
-              "PDA bump seed"
+              "Cumulative released_amount after the revocation"
             ],
-            "type": "u8"
+            "type": "u64"
+          },
+          {
+            "name": "revokedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingStatus",
+      "docs": [
+        "Lifecycle of a vesting: Created -> Funded -> Completed, or -> Revoked"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "created"
+          },
+          {
+            "name": "funded"
+          },
+          {
+            "name": "revoked"
+          },
+          {
+            "name": "completed"
           }
         ]
       }
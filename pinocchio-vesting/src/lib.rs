@@ -4,27 +4,199 @@ use pinocchio::{
     error::ProgramError,
     sysvars::{clock::Clock, rent::Rent, Sysvar},
 };
-use pinocchio_system::instructions::CreateAccount;
-use pinocchio_token::instructions::TransferChecked;
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+use pinocchio_token::instructions::{CloseAccount, TransferChecked};
 
 // ─────────────────────────────────────────────
 // Constants
 // ─────────────────────────────────────────────
 
-/// Vesting account data size (no Anchor discriminator)
-const VESTING_SIZE: usize = 145;
+/// Vesting account data size: discriminator + fields, byte-identical to Anchor's `VestingAccount`
+const VESTING_SIZE: usize = 474;
+
+/// Size of the headerless layout used before the discriminator was added
+const HEADERLESS_VESTING_SIZE: usize = 465;
+
+/// Size of the original (pre-revocation, admin-less seeds) vesting layout
+const LEGACY_VESTING_SIZE: usize = 145;
+
+/// Anchor's `VestingAccount` discriminator: sha256("account:VestingAccount")[..8]
+const VESTING_DISC: [u8; 8] = [102, 73, 10, 233, 200, 188, 228, 216];
+
+/// Anchor's `VESTING_LAYOUT_VERSION`; accounts of any other version are rejected
+const VESTING_LAYOUT_VERSION: u8 = 1;
 
 // Account data field offsets
-const ADMIN_OFF: usize = 0;
-const BENEFICIARY_OFF: usize = 32;
-const MINT_OFF: usize = 64;
-const TOTAL_AMOUNT_OFF: usize = 96;
-const RELEASED_AMOUNT_OFF: usize = 104;
-const START_TIME_OFF: usize = 112;
-const CLIFF_TIME_OFF: usize = 120;
-const END_TIME_OFF: usize = 128;
-const SEED_OFF: usize = 136;
-const BUMP_OFF: usize = 144;
+const DISC_OFF: usize = 0;
+const ADMIN_OFF: usize = 8;
+const BENEFICIARY_OFF: usize = 40;
+const MINT_OFF: usize = 72;
+const TOTAL_AMOUNT_OFF: usize = 104;
+const RELEASED_AMOUNT_OFF: usize = 112;
+const START_TIME_OFF: usize = 120;
+const CLIFF_TIME_OFF: usize = 128;
+const END_TIME_OFF: usize = 136;
+const SEED_OFF: usize = 144;
+const BUMP_OFF: usize = 152;
+const REVOCABLE_OFF: usize = 153;
+const REVOKED_AT_OFF: usize = 154;
+const STATUS_OFF: usize = 162;
+const LEGACY_SEEDS_OFF: usize = 163;
+const SCHEDULE_KIND_OFF: usize = 164;
+const PERIOD_SECONDS_OFF: usize = 165;
+const SCHEDULE_LEN_OFF: usize = 173;
+const SCHEDULE_POINTS_OFF: usize = 174;
+const CLIFF_AMOUNT_OFF: usize = 302;
+const ACCRUE_FROM_CLIFF_OFF: usize = 310;
+const ALLOW_PERMISSIONLESS_CLAIM_OFF: usize = 311;
+const CLAIM_DELEGATE_OFF: usize = 312;
+const PENDING_ADMIN_OFF: usize = 344;
+const SEED_ADMIN_OFF: usize = 376;
+const TRANSFERABLE_OFF: usize = 408;
+const SEED_BENEFICIARY_OFF: usize = 409;
+// NFT receipts are only minted by the Anchor program; always zero here
+const RECEIPT_MINT_OFF: usize = 441;
+const LAYOUT_VERSION_OFF: usize = 473;
+
+// Custom schedule table: MAX_SCHEDULE_POINTS x (timestamp i64, cumulative amount u64)
+const MAX_SCHEDULE_POINTS: usize = 8;
+const SCHEDULE_POINT_SIZE: usize = 16;
+
+// Vesting status values (same variant order as Anchor's `VestingStatus`)
+const STATUS_CREATED: u8 = 0;
+const STATUS_FUNDED: u8 = 1;
+const STATUS_REVOKED: u8 = 2;
+const STATUS_COMPLETED: u8 = 3;
+
+// Schedule kinds (same variant order as Anchor's `ScheduleKind`)
+const SCHEDULE_LINEAR: u8 = 0;
+const SCHEDULE_PERIODIC: u8 = 1;
+const SCHEDULE_CUSTOM_LINEAR: u8 = 2;
+const SCHEDULE_CUSTOM_STEP: u8 = 3;
+
+// Program config PDA (seeds ["config"]): paused(1) + pause_flags(1) + bump(1)
+// + treasury(32) + creation_fee_lamports(8) + deposit_fee_bps(2)
+// + fee_exempt_len(1) + fee_exempt(8 x 32)
+const CONFIG_SIZE: usize = 302;
+const CONFIG_PAUSED_OFF: usize = 0;
+const CONFIG_PAUSE_FLAGS_OFF: usize = 1;
+const CONFIG_BUMP_OFF: usize = 2;
+const CONFIG_TREASURY_OFF: usize = 3;
+const CONFIG_CREATION_FEE_OFF: usize = 35;
+const CONFIG_DEPOSIT_FEE_BPS_OFF: usize = 43;
+const CONFIG_FEE_EXEMPT_LEN_OFF: usize = 45;
+const CONFIG_FEE_EXEMPT_OFF: usize = 46;
+
+// Fee-exempt (partner) admin slots and 100% in basis points
+const MAX_FEE_EXEMPT: usize = 8;
+const MAX_FEE_BPS: u16 = 10_000;
+
+// pause_flags bits (same as Anchor's PAUSE_*); `paused` stops every instruction
+const PAUSE_CREATE: u8 = 1 << 0;
+const PAUSE_DEPOSIT: u8 = 1 << 1;
+const PAUSE_CLAIM: u8 = 1 << 2;
+
+/// Anchor's `ErrorCode::ConstraintSeeds`, returned for a non-canonical or mismatched PDA
+const ERROR_CONSTRAINT_SEEDS: u32 = 2006;
+/// Anchor's `ErrorCode::AccountDiscriminatorMismatch`
+const ERROR_ACCOUNT_DISCRIMINATOR_MISMATCH: u32 = 3002;
+/// Anchor's `ErrorCode::AccountDidNotDeserialize`, returned for a wrong size or layout version
+const ERROR_ACCOUNT_DID_NOT_DESERIALIZE: u32 = 3003;
+
+/// Same variants and codes as the Anchor program's `VestingError`, so clients
+/// decode a `Custom(n)` failure identically from either program. Variants only
+/// the Anchor program returns are kept so the numbering stays in lockstep.
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+enum VestingError {
+    InvalidTimeRange = 6000,
+    InvalidAmount,
+    UnauthorizedAdmin,
+    UnauthorizedBeneficiary,
+    AlreadyFunded,
+    NotFunded,
+    NothingToClaim,
+    MintMismatch,
+    DepositAmountMismatch,
+    Overflow,
+    NotRevocable,
+    AlreadyRevoked,
+    VestingNotComplete,
+    InvalidStatus,
+    InvalidLegacyAccount,
+    InvalidPeriod,
+    InvalidSchedule,
+    InvalidCliffAmount,
+    ClaimAmountExceedsClaimable,
+    PermissionlessClaimDisabled,
+    NotPendingAdmin,
+    NotTransferable,
+    ProgramPaused,
+    UnauthorizedConfigAuthority,
+    InvalidFeeConfig,
+    MissingTreasuryAccount,
+    InvalidBatchAccounts,
+    InvalidMerkleProof,
+    NotAllowedWithReceipt,
+    InvalidReceipt,
+}
+
+impl From<VestingError> for ProgramError {
+    fn from(e: VestingError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+// BPF upgradeable loader (BPFLoaderUpgradeab1e11111111111111111111111)
+const BPF_LOADER_UPGRADEABLE_ID: Address = Address::new_from_array([
+    2, 168, 246, 145, 78, 136, 161, 176, 226, 16, 21, 62, 247, 99, 174, 43,
+    0, 194, 185, 61, 22, 193, 36, 210, 192, 83, 122, 16, 4, 128, 0, 0,
+]);
+
+// ProgramData account: tag(4) + slot(8) + Option<upgrade_authority>(1 + 32)
+const PROGRAM_DATA_AUTHORITY_OFF: usize = 12;
+
+// Associated Token Account program (ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL)
+const ATA_PROGRAM_ID: Address = Address::new_from_array([
+    140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131,
+    11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89,
+]);
+
+// SPL token account layout
+const TOKEN_ACCOUNT_SIZE: usize = 165;
+const TOKEN_MINT_OFF: usize = 0;
+const TOKEN_OWNER_OFF: usize = 32;
+const TOKEN_AMOUNT_OFF: usize = 64;
+
+// SPL mint layout
+const MINT_SIZE: usize = 82;
+const MINT_DECIMALS_OFF: usize = 44;
+
+// Anchor event discriminators: sha256("event:<Name>")[..8]
+const VESTING_CREATED_DISC: [u8; 8] = [181, 223, 229, 220, 204, 6, 169, 125];
+const VESTING_FUNDED_DISC: [u8; 8] = [22, 228, 106, 171, 205, 204, 38, 238];
+const TOKENS_CLAIMED_DISC: [u8; 8] = [25, 128, 244, 55, 241, 136, 200, 91];
+const VESTING_REVOKED_DISC: [u8; 8] = [215, 148, 193, 127, 237, 245, 90, 75];
+const VESTING_CLOSED_DISC: [u8; 8] = [141, 146, 62, 52, 160, 60, 177, 123];
+const CLAIM_DELEGATE_UPDATED_DISC: [u8; 8] = [171, 91, 60, 247, 254, 62, 141, 226];
+const ADMIN_PROPOSED_DISC: [u8; 8] = [129, 249, 226, 227, 199, 82, 110, 243];
+const ADMIN_TRANSFERRED_DISC: [u8; 8] = [255, 147, 182, 5, 199, 217, 38, 179];
+const BENEFICIARY_TRANSFERRED_DISC: [u8; 8] = [70, 88, 246, 222, 226, 28, 252, 39];
+const CONFIG_UPDATED_DISC: [u8; 8] = [40, 241, 230, 122, 11, 19, 198, 194];
+
+// Event payload sizes (discriminator + borsh-encoded fields)
+const VESTING_CREATED_SIZE: usize = 8 + 32 * 4 + 8 * 5 + 1 + 1 + 8 + 8 + 1 + 1 + 1 + 8;
+const VESTING_FUNDED_SIZE: usize = 8 + 32 * 3 + 8 * 5;
+const TOKENS_CLAIMED_SIZE: usize = 8 + 32 * 3 + 8 * 6;
+const VESTING_REVOKED_SIZE: usize = 8 + 32 * 4 + 8 * 5;
+const VESTING_CLOSED_SIZE: usize = 8 + 32 * 3 + 8;
+const CLAIM_DELEGATE_UPDATED_SIZE: usize = 8 + 32 * 3;
+const ADMIN_PROPOSED_SIZE: usize = 8 + 32 * 3;
+const ADMIN_TRANSFERRED_SIZE: usize = 8 + 32 * 3;
+const BENEFICIARY_TRANSFERRED_SIZE: usize = 8 + 32 * 3 + 1;
+const CONFIG_UPDATED_SIZE: usize = 8 + 32 + 1 + 1 + 32 + 8 + 2 + 1;
 
 // ─────────────────────────────────────────────
 // Entrypoint
@@ -44,6 +216,19 @@ pub fn process_instruction(
         0 => process_create_vesting(program_id, accounts, &data[1..]),
         1 => process_deposit(program_id, accounts, &data[1..]),
         2 => process_claim(program_id, accounts, &data[1..]),
+        3 => process_revoke(program_id, accounts, &data[1..]),
+        4 => process_close_vesting(program_id, accounts, &data[1..]),
+        5 => process_migrate_vesting(program_id, accounts, &data[1..]),
+        6 => process_create_and_fund_vesting(program_id, accounts, &data[1..]),
+        7 => process_claim_to(program_id, accounts, &data[1..]),
+        8 => process_crank_claim(program_id, accounts, &data[1..]),
+        9 => process_set_claim_delegate(program_id, accounts, &data[1..]),
+        10 => process_propose_admin(program_id, accounts, &data[1..]),
+        11 => process_accept_admin(program_id, accounts, &data[1..]),
+        12 => process_transfer_beneficiary(program_id, accounts, &data[1..]),
+        13 => process_initialize_config(program_id, accounts, &data[1..]),
+        14 => process_update_config(program_id, accounts, &data[1..]),
+        15 => process_create_vesting_batch(program_id, accounts, &data[1..]),
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
@@ -51,15 +236,22 @@ pub fn process_instruction(
 // ─────────────────────────────────────────────
 // Instruction 0: Create Vesting
 // ─────────────────────────────────────────────
-// Data: seed(8) + total_amount(8) + start_time(8) + cliff_time(8) + end_time(8) + bump(1) = 41 bytes
-// Accounts: [admin(s,w), beneficiary, mint, vesting_account(w), system_program]
+// Data: seed(8) + total_amount(8) + start_time(8) + cliff_time(8) + end_time(8) + bump(1)
+//       + revocable(1) + schedule_kind(1) + period_seconds(8) + cliff_amount(8)
+//       + accrue_from_cliff(1) + allow_permissionless_claim(1) + transferable(1)
+//       + point_count(1) + point_count x (timestamp(8) + amount(8))
+//       = 63 + 16 * point_count bytes
+// Accounts: [admin(s,w), beneficiary, mint, vesting_account(w), vault(w), system_program,
+//            token_program, associated_token_program, config,
+//            treasury(w, only when a creation fee is charged)]
+// The vault is created here as the vesting's ATA if missing, paid by the admin.
 
 fn process_create_vesting(
     program_id: &Address,
     accounts: &[AccountView],
     data: &[u8],
 ) -> ProgramResult {
-    if accounts.len() < 5 || data.len() < 41 {
+    if accounts.len() < 9 {
         return Err(ProgramError::InvalidInstructionData);
     }
 
@@ -67,7 +259,45 @@ fn process_create_vesting(
     let beneficiary = &accounts[1];
     let mint = &accounts[2];
     let vesting_account = &accounts[3];
-    let _system_program = &accounts[4];
+    let vault = &accounts[4];
+    let system_program = &accounts[5];
+    let token_program = &accounts[6];
+    let associated_token_program = &accounts[7];
+    let config = &accounts[8];
+    let treasury = accounts.get(9);
+
+    check_config(program_id, config, PAUSE_CREATE)?;
+    check_token_program(token_program)?;
+    check_associated_token_program(associated_token_program)?;
+
+    let creation_fee = collect_creation_fee(config, admin, treasury)?;
+
+    create_vesting(
+        program_id,
+        admin,
+        beneficiary.address(),
+        mint,
+        vesting_account,
+        data,
+        creation_fee,
+    )?;
+
+    create_vault(admin, vault, vesting_account, mint, system_program, token_program)
+}
+
+/// Validate the schedule, create the vesting PDA and write its initial state
+fn create_vesting(
+    program_id: &Address,
+    admin: &AccountView,
+    beneficiary: &Address,
+    mint: &AccountView,
+    vesting_account: &AccountView,
+    data: &[u8],
+    creation_fee: u64,
+) -> ProgramResult {
+    if data.len() < 63 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
 
     // Parse instruction data
     let seed = u64::from_le_bytes(data[0..8].try_into().unwrap());
@@ -76,6 +306,18 @@ fn process_create_vesting(
     let cliff_time = i64::from_le_bytes(data[24..32].try_into().unwrap());
     let end_time = i64::from_le_bytes(data[32..40].try_into().unwrap());
     let bump = data[40];
+    let revocable = data[41];
+    let schedule_kind = data[42];
+    let period_seconds = u64::from_le_bytes(data[43..51].try_into().unwrap());
+    let cliff_amount = u64::from_le_bytes(data[51..59].try_into().unwrap());
+    let accrue_from_cliff = data[59];
+    let allow_permissionless_claim = data[60];
+    let transferable = data[61];
+    let point_count = data[62] as usize;
+    if point_count > MAX_SCHEDULE_POINTS || data.len() < 63 + point_count * SCHEDULE_POINT_SIZE {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let points = &data[63..63 + point_count * SCHEDULE_POINT_SIZE];
 
     // Validate signer
     if !admin.is_signer() {
@@ -83,43 +325,86 @@ fn process_create_vesting(
     }
 
     // Validate parameters
+    if revocable > 1 || accrue_from_cliff > 1 || allow_permissionless_claim > 1 || transferable > 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
     if total_amount == 0 {
-        return Err(ProgramError::InvalidArgument);
+        return Err(VestingError::InvalidAmount.into());
     }
     if !(start_time <= cliff_time && cliff_time <= end_time && start_time < end_time) {
-        return Err(ProgramError::InvalidArgument);
+        return Err(VestingError::InvalidTimeRange.into());
+    }
+    if cliff_amount > total_amount {
+        return Err(VestingError::InvalidCliffAmount.into());
+    }
+    let accrual_start = if accrue_from_cliff == 1 { cliff_time } else { start_time };
+    match schedule_kind {
+        SCHEDULE_LINEAR => {
+            if period_seconds != 0 {
+                return Err(VestingError::InvalidPeriod.into());
+            }
+        }
+        SCHEDULE_PERIODIC => {
+            if period_seconds == 0 || period_seconds > (end_time - accrual_start) as u64 {
+                return Err(VestingError::InvalidPeriod.into());
+            }
+        }
+        SCHEDULE_CUSTOM_LINEAR | SCHEDULE_CUSTOM_STEP => {
+            // The table alone defines a custom schedule
+            if period_seconds != 0 {
+                return Err(VestingError::InvalidPeriod.into());
+            }
+            if cliff_amount != 0 {
+                return Err(VestingError::InvalidCliffAmount.into());
+            }
+            if accrue_from_cliff != 0
+                || !schedule_points_valid(points, start_time, end_time, total_amount)
+            {
+                return Err(VestingError::InvalidSchedule.into());
+            }
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+    if matches!(schedule_kind, SCHEDULE_LINEAR | SCHEDULE_PERIODIC) && point_count != 0 {
+        return Err(VestingError::InvalidSchedule.into());
     }
 
-    // Build PDA signer seeds
+    // Only the canonical bump is accepted, so each (admin, beneficiary, mint, seed)
+    // maps to exactly one vesting, as with Anchor's `bump` constraint
     let seed_bytes = seed.to_le_bytes();
+    let (expected, canonical_bump) = Address::find_program_address(
+        &[
+            b"vesting",
+            admin.address().as_ref(),
+            beneficiary.as_ref(),
+            mint.address().as_ref(),
+            &seed_bytes,
+        ],
+        program_id,
+    );
+    if bump != canonical_bump || expected != *vesting_account.address() {
+        return Err(ProgramError::Custom(ERROR_CONSTRAINT_SEEDS));
+    }
+
+    // Build PDA signer seeds
     let bump_bytes = [bump];
     let seeds = [
         Seed::from(b"vesting" as &[u8]),
-        Seed::from(beneficiary.address().as_ref()),
+        Seed::from(admin.address().as_ref()),
+        Seed::from(beneficiary.as_ref()),
         Seed::from(mint.address().as_ref()),
         Seed::from(&seed_bytes as &[u8]),
         Seed::from(&bump_bytes as &[u8]),
     ];
-    let signer = Signer::from(&seeds);
 
     // Create the vesting PDA account via system program CPI
-    let rent = Rent::get()?;
-    #[allow(deprecated)]
-    let lamports = rent.minimum_balance(VESTING_SIZE);
-
-    CreateAccount {
-        from: admin,
-        to: vesting_account,
-        lamports,
-        space: VESTING_SIZE as u64,
-        owner: program_id,
-    }
-    .invoke_signed(&[signer])?;
+    create_pda_account(admin, vesting_account, VESTING_SIZE, program_id, &seeds)?;
 
     // Write vesting account data
     let mut acct_data = vesting_account.try_borrow_mut()?;
+    acct_data[DISC_OFF..DISC_OFF + 8].copy_from_slice(&VESTING_DISC);
     acct_data[ADMIN_OFF..ADMIN_OFF + 32].copy_from_slice(admin.address().as_ref());
-    acct_data[BENEFICIARY_OFF..BENEFICIARY_OFF + 32].copy_from_slice(beneficiary.address().as_ref());
+    acct_data[BENEFICIARY_OFF..BENEFICIARY_OFF + 32].copy_from_slice(beneficiary.as_ref());
     acct_data[MINT_OFF..MINT_OFF + 32].copy_from_slice(mint.address().as_ref());
     acct_data[TOTAL_AMOUNT_OFF..TOTAL_AMOUNT_OFF + 8].copy_from_slice(&total_amount.to_le_bytes());
     acct_data[RELEASED_AMOUNT_OFF..RELEASED_AMOUNT_OFF + 8].copy_from_slice(&0u64.to_le_bytes());
@@ -128,9 +413,46 @@ fn process_create_vesting(
     acct_data[END_TIME_OFF..END_TIME_OFF + 8].copy_from_slice(&end_time.to_le_bytes());
     acct_data[SEED_OFF..SEED_OFF + 8].copy_from_slice(&seed.to_le_bytes());
     acct_data[BUMP_OFF] = bump;
+    acct_data[REVOCABLE_OFF] = revocable;
+    acct_data[REVOKED_AT_OFF..REVOKED_AT_OFF + 8].copy_from_slice(&0i64.to_le_bytes());
+    acct_data[STATUS_OFF] = STATUS_CREATED;
+    acct_data[LEGACY_SEEDS_OFF] = 0;
+    acct_data[SCHEDULE_KIND_OFF] = schedule_kind;
+    acct_data[PERIOD_SECONDS_OFF..PERIOD_SECONDS_OFF + 8].copy_from_slice(&period_seconds.to_le_bytes());
+    acct_data[SCHEDULE_LEN_OFF] = point_count as u8;
+    acct_data[SCHEDULE_POINTS_OFF..SCHEDULE_POINTS_OFF + points.len()].copy_from_slice(points);
+    acct_data[CLIFF_AMOUNT_OFF..CLIFF_AMOUNT_OFF + 8].copy_from_slice(&cliff_amount.to_le_bytes());
+    acct_data[ACCRUE_FROM_CLIFF_OFF] = accrue_from_cliff;
+    acct_data[ALLOW_PERMISSIONLESS_CLAIM_OFF] = allow_permissionless_claim;
+    acct_data[CLAIM_DELEGATE_OFF..CLAIM_DELEGATE_OFF + 32].fill(0);
+    acct_data[PENDING_ADMIN_OFF..PENDING_ADMIN_OFF + 32].fill(0);
+    acct_data[SEED_ADMIN_OFF..SEED_ADMIN_OFF + 32].copy_from_slice(admin.address().as_ref());
+    acct_data[TRANSFERABLE_OFF] = transferable;
+    acct_data[SEED_BENEFICIARY_OFF..SEED_BENEFICIARY_OFF + 32]
+        .copy_from_slice(beneficiary.as_ref());
+    acct_data[RECEIPT_MINT_OFF..RECEIPT_MINT_OFF + 32].fill(0);
+    acct_data[LAYOUT_VERSION_OFF] = VESTING_LAYOUT_VERSION;
     drop(acct_data);
 
-    solana_program_log::log("Vesting account created");
+    Event::<VESTING_CREATED_SIZE>::new(&VESTING_CREATED_DISC)
+        .push(vesting_account.address().as_ref())
+        .push(admin.address().as_ref())
+        .push(beneficiary.as_ref())
+        .push(mint.address().as_ref())
+        .push(&seed.to_le_bytes())
+        .push(&total_amount.to_le_bytes())
+        .push(&start_time.to_le_bytes())
+        .push(&cliff_time.to_le_bytes())
+        .push(&end_time.to_le_bytes())
+        .push(&[revocable])
+        .push(&[schedule_kind])
+        .push(&period_seconds.to_le_bytes())
+        .push(&cliff_amount.to_le_bytes())
+        .push(&[accrue_from_cliff])
+        .push(&[allow_permissionless_claim])
+        .push(&[transferable])
+        .push(&creation_fee.to_le_bytes())
+        .emit();
     Ok(())
 }
 
@@ -138,14 +460,17 @@ fn process_create_vesting(
 // Instruction 1: Deposit
 // ─────────────────────────────────────────────
 // Data: empty
-// Accounts: [admin(s,w), mint, vesting_account, vault(w), admin_token_account(w), token_program]
+// Accounts: [admin(s,w), mint, vesting_account(w), vault(w), admin_token_account(w), token_program,
+//            config, treasury_token_account(w, only when a deposit fee is charged)]
+// vault and admin_token_account must be the vesting's and the admin's ATAs; token_program
+// must be SPL Token.
 
 fn process_deposit(
     program_id: &Address,
     accounts: &[AccountView],
     _data: &[u8],
 ) -> ProgramResult {
-    if accounts.len() < 6 {
+    if accounts.len() < 7 {
         return Err(ProgramError::InvalidInstructionData);
     }
 
@@ -154,53 +479,83 @@ fn process_deposit(
     let vesting_account = &accounts[2];
     let vault = &accounts[3];
     let admin_token_account = &accounts[4];
-    let _token_program = &accounts[5];
+    let token_program = &accounts[5];
+    let config = &accounts[6];
+    let treasury_token_account = accounts.get(7);
+
+    check_config(program_id, config, PAUSE_DEPOSIT)?;
+    check_token_program(token_program)?;
+    ata_amount(admin_token_account, mint.address().as_array(), admin.address().as_array())?;
 
+    deposit(
+        program_id,
+        admin,
+        mint,
+        vesting_account,
+        vault,
+        admin_token_account,
+        config,
+        treasury_token_account,
+    )
+}
+
+/// Move total_amount from the admin into the vault and mark the vesting funded.
+/// The protocol fee is paid on top, so the vault always holds exactly total_amount.
+/// Callers check `admin_token_account` is the admin's ATA for `mint` beforehand,
+/// so a batch derives it once rather than per vesting.
+#[allow(clippy::too_many_arguments)]
+fn deposit(
+    program_id: &Address,
+    admin: &AccountView,
+    mint: &AccountView,
+    vesting_account: &AccountView,
+    vault: &AccountView,
+    admin_token_account: &AccountView,
+    config: &AccountView,
+    treasury_token_account: Option<&AccountView>,
+) -> ProgramResult {
     // Validate signer
     if !admin.is_signer() {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    // Validate ownership
-    if !vesting_account.owned_by(program_id) {
-        return Err(ProgramError::InvalidAccountOwner);
-    }
+    // Validate ownership, discriminator and layout version
+    check_vesting(program_id, vesting_account)?;
 
     // Read vesting data (copy to locals, then drop borrow)
-    let (stored_admin, stored_mint, total_amount) = {
+    let (stored_admin, stored_mint, total_amount, seed, status) = {
         let d = vesting_account.try_borrow()?;
         let mut a = [0u8; 32];
         a.copy_from_slice(&d[ADMIN_OFF..ADMIN_OFF + 32]);
         let mut m = [0u8; 32];
         m.copy_from_slice(&d[MINT_OFF..MINT_OFF + 32]);
         let t = u64::from_le_bytes(d[TOTAL_AMOUNT_OFF..TOTAL_AMOUNT_OFF + 8].try_into().unwrap());
-        (a, m, t)
+        let sd = u64::from_le_bytes(d[SEED_OFF..SEED_OFF + 8].try_into().unwrap());
+        let st = d[STATUS_OFF];
+        (a, m, t, sd, st)
     };
 
     // Verify admin
     if stored_admin != *admin.address().as_array() {
-        return Err(ProgramError::InvalidAccountData);
+        return Err(VestingError::UnauthorizedAdmin.into());
     }
 
     // Verify mint
     if stored_mint != *mint.address().as_array() {
-        return Err(ProgramError::InvalidAccountData);
+        return Err(VestingError::MintMismatch.into());
     }
 
-    // Check vault is empty (SPL token account amount at offset 64)
-    let vault_amount = {
-        let d = vault.try_borrow()?;
-        u64::from_le_bytes(d[64..72].try_into().unwrap())
-    };
-    if vault_amount != 0 {
-        return Err(ProgramError::InvalidAccountData);
+    // Ensure not already funded (tokens sent straight to the vault don't count)
+    if status == STATUS_REVOKED {
+        return Err(VestingError::AlreadyRevoked.into());
+    }
+    if status != STATUS_CREATED {
+        return Err(VestingError::AlreadyFunded.into());
     }
 
-    // Read mint decimals (offset 44 in Mint layout)
-    let decimals = {
-        let d = mint.try_borrow()?;
-        d[44]
-    };
+    // Vault must be the vesting's ATA for this mint
+    let vault_amount = ata_amount(vault, &stored_mint, vesting_account.address().as_array())?;
+    let decimals = mint_decimals(mint)?;
 
     // Transfer total_amount from admin to vault
     TransferChecked {
@@ -213,47 +568,144 @@ fn process_deposit(
     }
     .invoke()?;
 
-    solana_program_log::log("Tokens deposited into vault");
+    // Transfer the protocol fee from admin to treasury
+    let fee_amount = deposit_fee(config, admin, total_amount)?;
+    if fee_amount > 0 {
+        let treasury_token_account = treasury_token_account
+            .ok_or(ProgramError::from(VestingError::MissingTreasuryAccount))?;
+        let mut treasury = [0u8; 32];
+        treasury.copy_from_slice(
+            &config.try_borrow()?[CONFIG_TREASURY_OFF..CONFIG_TREASURY_OFF + 32],
+        );
+        token_account_amount(treasury_token_account, &stored_mint, Some(&treasury))?;
+        TransferChecked {
+            from: admin_token_account,
+            mint,
+            to: treasury_token_account,
+            authority: admin,
+            amount: fee_amount,
+            decimals,
+        }
+        .invoke()?;
+    }
+
+    {
+        let mut data = vesting_account.try_borrow_mut()?;
+        data[STATUS_OFF] = STATUS_FUNDED;
+    }
+
+    let vault_balance = vault_amount
+        .checked_add(total_amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let clock = Clock::get()?;
+    Event::<VESTING_FUNDED_SIZE>::new(&VESTING_FUNDED_DISC)
+        .push(vesting_account.address().as_ref())
+        .push(&stored_admin)
+        .push(&stored_mint)
+        .push(&seed.to_le_bytes())
+        .push(&total_amount.to_le_bytes())
+        .push(&vault_balance.to_le_bytes())
+        .push(&fee_amount.to_le_bytes())
+        .push(&clock.unix_timestamp.to_le_bytes())
+        .emit();
     Ok(())
 }
 
 // ─────────────────────────────────────────────
 // Instruction 2: Claim
 // ─────────────────────────────────────────────
-// Data: empty
-// Accounts: [beneficiary(s,w), mint, vesting_account(w), vault(w),
-//            beneficiary_token_account(w), token_program]
+// Data: empty (claim everything claimable) or amount(8)
+// Accounts: [claimer(s,w), beneficiary, mint, vesting_account(w), vault(w),
+//            beneficiary_token_account(w), system_program, token_program,
+//            associated_token_program, config]
+// The claimer is the beneficiary or its registered claim delegate. vault and
+// beneficiary_token_account must be the vesting's and the beneficiary's ATAs;
+// the latter is created if missing, paid by the claimer (Anchor's `init_if_needed`).
 
 fn process_claim(
     program_id: &Address,
     accounts: &[AccountView],
-    _data: &[u8],
+    data: &[u8],
 ) -> ProgramResult {
-    if accounts.len() < 6 {
+    if accounts.len() < 10 {
         return Err(ProgramError::InvalidInstructionData);
     }
 
-    let beneficiary = &accounts[0];
-    let mint = &accounts[1];
-    let vesting_account = &accounts[2];
-    let vault = &accounts[3];
-    let beneficiary_ata = &accounts[4];
-    let _token_program = &accounts[5];
+    let claimer = &accounts[0];
+    let beneficiary = &accounts[1];
+    let mint = &accounts[2];
+    let vesting_account = &accounts[3];
+    let vault = &accounts[4];
+    let beneficiary_ata = &accounts[5];
+    let system_program = &accounts[6];
+    let token_program = &accounts[7];
+    let associated_token_program = &accounts[8];
+    let config = &accounts[9];
+
+    check_config(program_id, config, PAUSE_CLAIM)?;
+    check_token_program(token_program)?;
+    check_associated_token_program(associated_token_program)?;
+
+    // The ATA is derived from the beneficiary account, so it must be the stored one
+    check_vesting(program_id, vesting_account)?;
+    if vesting_account.try_borrow()?[BENEFICIARY_OFF..BENEFICIARY_OFF + 32]
+        != *beneficiary.address().as_array()
+    {
+        return Err(VestingError::UnauthorizedBeneficiary.into());
+    }
+
+    CreateIdempotent {
+        funding_account: claimer,
+        account: beneficiary_ata,
+        wallet: beneficiary,
+        mint,
+        system_program,
+        token_program,
+    }
+    .invoke()?;
+
+    claim(program_id, claimer, mint, vesting_account, vault, beneficiary_ata, data, ClaimMode::Claim)
+}
 
+/// Who may trigger a release, and where the tokens may go
+#[derive(Clone, Copy, PartialEq)]
+enum ClaimMode {
+    /// Beneficiary or claim delegate; beneficiary's canonical ATA only
+    Claim,
+    /// Beneficiary only; any token account of the vesting mint
+    ClaimTo,
+    /// Anyone, if the vesting opted in; beneficiary's canonical ATA only
+    Crank,
+}
+
+/// Release `amount` (or everything claimable) from the vault to `destination`,
+/// after checking `authority` and `destination` against `mode`
+#[allow(clippy::too_many_arguments)]
+fn claim(
+    program_id: &Address,
+    authority: &AccountView,
+    mint: &AccountView,
+    vesting_account: &AccountView,
+    vault: &AccountView,
+    destination: &AccountView,
+    data: &[u8],
+    mode: ClaimMode,
+) -> ProgramResult {
     // Validate signer
-    if !beneficiary.is_signer() {
+    if !authority.is_signer() {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    // Validate ownership
-    if !vesting_account.owned_by(program_id) {
-        return Err(ProgramError::InvalidAccountOwner);
-    }
+    // Validate ownership, discriminator and layout version
+    check_vesting(program_id, vesting_account)?;
 
     // Read all vesting data (copy to locals, then drop borrow)
-    let (stored_beneficiary, stored_mint, total_amount, released_amount,
-         start_time, cliff_time, end_time, seed, bump) = {
+    let (seed_admin, stored_beneficiary, stored_mint, total_amount, released_amount,
+         start_time, cliff_time, end_time, seed, bump, status, legacy, allow_permissionless,
+         claim_delegate, seed_beneficiary, schedule) = {
         let d = vesting_account.try_borrow()?;
+        let mut a = [0u8; 32];
+        a.copy_from_slice(&d[SEED_ADMIN_OFF..SEED_ADMIN_OFF + 32]);
         let mut b = [0u8; 32];
         b.copy_from_slice(&d[BENEFICIARY_OFF..BENEFICIARY_OFF + 32]);
         let mut m = [0u8; 32];
@@ -265,100 +717,1538 @@ fn process_claim(
         let et = i64::from_le_bytes(d[END_TIME_OFF..END_TIME_OFF + 8].try_into().unwrap());
         let sd = u64::from_le_bytes(d[SEED_OFF..SEED_OFF + 8].try_into().unwrap());
         let bp = d[BUMP_OFF];
-        (b, m, ta, ra, st, ct, et, sd, bp)
+        let ss = d[STATUS_OFF];
+        let lg = d[LEGACY_SEEDS_OFF] != 0;
+        let ap = d[ALLOW_PERMISSIONLESS_CLAIM_OFF] != 0;
+        let mut cd = [0u8; 32];
+        cd.copy_from_slice(&d[CLAIM_DELEGATE_OFF..CLAIM_DELEGATE_OFF + 32]);
+        let mut sb = [0u8; 32];
+        sb.copy_from_slice(&d[SEED_BENEFICIARY_OFF..SEED_BENEFICIARY_OFF + 32]);
+        let sc = Schedule::read(&d);
+        (a, b, m, ta, ra, st, ct, et, sd, bp, ss, lg, ap, cd, sb, sc)
     };
 
-    // Verify beneficiary
-    if stored_beneficiary != *beneficiary.address().as_array() {
-        return Err(ProgramError::InvalidAccountData);
+    // Verify the caller
+    let signer_key = authority.address().as_array();
+    match mode {
+        ClaimMode::Claim => {
+            let is_delegate = claim_delegate != [0u8; 32] && claim_delegate == *signer_key;
+            if stored_beneficiary != *signer_key && !is_delegate {
+                return Err(VestingError::UnauthorizedBeneficiary.into());
+            }
+        }
+        ClaimMode::ClaimTo => {
+            if stored_beneficiary != *signer_key {
+                return Err(VestingError::UnauthorizedBeneficiary.into());
+            }
+        }
+        ClaimMode::Crank => {
+            // Opt-in only
+            if !allow_permissionless {
+                return Err(VestingError::PermissionlessClaimDisabled.into());
+            }
+        }
     }
 
     // Verify mint
     if stored_mint != *mint.address().as_array() {
-        return Err(ProgramError::InvalidAccountData);
+        return Err(VestingError::MintMismatch.into());
     }
 
-    // Check vault is funded
-    let vault_amount = {
-        let d = vault.try_borrow()?;
-        u64::from_le_bytes(d[64..72].try_into().unwrap())
-    };
-    if vault_amount == 0 {
-        return Err(ProgramError::InvalidAccountData);
+    // Only Claim To may pay into an arbitrary token account for the vesting mint
+    match mode {
+        ClaimMode::Claim | ClaimMode::Crank => {
+            ata_amount(destination, &stored_mint, &stored_beneficiary)?;
+        }
+        ClaimMode::ClaimTo => {
+            token_account_amount(destination, &stored_mint, None)?;
+        }
+    }
+
+    // Ensure vesting is funded and still active; a completed one has nothing left
+    match status {
+        STATUS_FUNDED => {}
+        STATUS_CREATED => return Err(VestingError::NotFunded.into()),
+        STATUS_REVOKED => return Err(VestingError::AlreadyRevoked.into()),
+        _ => return Err(VestingError::NothingToClaim.into()),
     }
 
+    let vault_amount = ata_amount(vault, &stored_mint, vesting_account.address().as_array())?;
+
     // Get current time
     let clock = Clock::get()?;
     let now = clock.unix_timestamp;
 
     // Calculate released amount
-    let total_released = calculate_released(total_amount, start_time, cliff_time, end_time, now);
+    let total_released = calculate_released(total_amount, start_time, cliff_time, end_time, &schedule, now);
     let claimable = total_released.saturating_sub(released_amount);
 
-    if claimable == 0 {
-        return Err(ProgramError::InvalidAccountData);
+    // Optional partial claim amount: no data claims everything, otherwise exactly 8 bytes
+    let amount = match data.len() {
+        0 => claimable,
+        8 => u64::from_le_bytes(data.try_into().unwrap()),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+    if amount > claimable {
+        return Err(VestingError::ClaimAmountExceedsClaimable.into());
+    }
+    if amount == 0 {
+        return Err(VestingError::NothingToClaim.into());
     }
 
     // Build PDA signer seeds
     let seed_bytes = seed.to_le_bytes();
     let bump_bytes = [bump];
-    let seeds = [
-        Seed::from(b"vesting" as &[u8]),
-        Seed::from(beneficiary.address().as_ref()),
-        Seed::from(mint.address().as_ref()),
-        Seed::from(&seed_bytes as &[u8]),
-        Seed::from(&bump_bytes as &[u8]),
-    ];
-    let signer = Signer::from(&seeds);
+    let (seeds, seed_count) = vesting_signer_seeds(
+        &seed_admin, &seed_beneficiary, &stored_mint, &seed_bytes, &bump_bytes, legacy,
+    );
+    let signer = Signer::from(&seeds[..seed_count]);
 
-    // Read mint decimals
-    let decimals = {
-        let d = mint.try_borrow()?;
-        d[44]
-    };
+    let decimals = mint_decimals(mint)?;
 
-    // Transfer claimable tokens from vault to beneficiary
+    // Transfer the claimed tokens from vault to the destination
     TransferChecked {
         from: vault,
         mint,
-        to: beneficiary_ata,
+        to: destination,
         authority: vesting_account,
-        amount: claimable,
+        amount,
         decimals,
     }
     .invoke_signed(&[signer])?;
 
     // Update released_amount
+    let new_released = released_amount + amount;
     {
         let mut data = vesting_account.try_borrow_mut()?;
-        let new_released = released_amount + claimable;
         data[RELEASED_AMOUNT_OFF..RELEASED_AMOUNT_OFF + 8]
             .copy_from_slice(&new_released.to_le_bytes());
+        if new_released == total_amount {
+            data[STATUS_OFF] = STATUS_COMPLETED;
+        }
     }
 
-    solana_program_log::log("Tokens claimed successfully");
+    Event::<TOKENS_CLAIMED_SIZE>::new(&TOKENS_CLAIMED_DISC)
+        .push(vesting_account.address().as_ref())
+        .push(&stored_beneficiary)
+        .push(&stored_mint)
+        .push(&seed.to_le_bytes())
+        .push(&amount.to_le_bytes())
+        .push(&new_released.to_le_bytes())
+        .push(&total_amount.to_le_bytes())
+        .push(&(vault_amount - amount).to_le_bytes())
+        .push(&now.to_le_bytes())
+        .emit();
     Ok(())
 }
 
 // ─────────────────────────────────────────────
-// Helpers
+// Instruction 3: Revoke
 // ─────────────────────────────────────────────
+// Data: empty
+// Accounts: [admin(s,w), mint, vesting_account(w), vault(w),
+//            beneficiary_token_account(w), admin_token_account(w), token_program, config]
 
-fn calculate_released(
-    total_amount: u64,
-    start_time: i64,
-    cliff_time: i64,
-    end_time: i64,
-    now: i64,
-) -> u64 {
-    if now < cliff_time {
-        return 0;
+fn process_revoke(
+    program_id: &Address,
+    accounts: &[AccountView],
+    _data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
     }
-    if now >= end_time {
-        return total_amount;
+
+    let admin = &accounts[0];
+    let mint = &accounts[1];
+    let vesting_account = &accounts[2];
+    let vault = &accounts[3];
+    let beneficiary_ata = &accounts[4];
+    let admin_token_account = &accounts[5];
+    let token_program = &accounts[6];
+    let config = &accounts[7];
+
+    check_config(program_id, config, 0)?;
+    check_token_program(token_program)?;
+
+    // Validate signer
+    if !admin.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Validate ownership, discriminator and layout version
+    check_vesting(program_id, vesting_account)?;
+
+    // Read all vesting data (copy to locals, then drop borrow)
+    let (stored_admin, stored_beneficiary, stored_mint, total_amount, released_amount,
+         start_time, cliff_time, end_time, seed, bump, revocable, status, legacy, seed_admin,
+         seed_beneficiary, schedule) = {
+        let d = vesting_account.try_borrow()?;
+        let mut a = [0u8; 32];
+        a.copy_from_slice(&d[ADMIN_OFF..ADMIN_OFF + 32]);
+        let mut b = [0u8; 32];
+        b.copy_from_slice(&d[BENEFICIARY_OFF..BENEFICIARY_OFF + 32]);
+        let mut m = [0u8; 32];
+        m.copy_from_slice(&d[MINT_OFF..MINT_OFF + 32]);
+        let ta = u64::from_le_bytes(d[TOTAL_AMOUNT_OFF..TOTAL_AMOUNT_OFF + 8].try_into().unwrap());
+        let ra = u64::from_le_bytes(d[RELEASED_AMOUNT_OFF..RELEASED_AMOUNT_OFF + 8].try_into().unwrap());
+        let st = i64::from_le_bytes(d[START_TIME_OFF..START_TIME_OFF + 8].try_into().unwrap());
+        let ct = i64::from_le_bytes(d[CLIFF_TIME_OFF..CLIFF_TIME_OFF + 8].try_into().unwrap());
+        let et = i64::from_le_bytes(d[END_TIME_OFF..END_TIME_OFF + 8].try_into().unwrap());
+        let sd = u64::from_le_bytes(d[SEED_OFF..SEED_OFF + 8].try_into().unwrap());
+        let bp = d[BUMP_OFF];
+        let rc = d[REVOCABLE_OFF];
+        let ss = d[STATUS_OFF];
+        let lg = d[LEGACY_SEEDS_OFF] != 0;
+        let mut sa = [0u8; 32];
+        sa.copy_from_slice(&d[SEED_ADMIN_OFF..SEED_ADMIN_OFF + 32]);
+        let mut sb = [0u8; 32];
+        sb.copy_from_slice(&d[SEED_BENEFICIARY_OFF..SEED_BENEFICIARY_OFF + 32]);
+        let sc = Schedule::read(&d);
+        (a, b, m, ta, ra, st, ct, et, sd, bp, rc, ss, lg, sa, sb, sc)
+    };
+
+    // Verify admin
+    if stored_admin != *admin.address().as_array() {
+        return Err(VestingError::UnauthorizedAdmin.into());
+    }
+
+    // Verify mint
+    if stored_mint != *mint.address().as_array() {
+        return Err(VestingError::MintMismatch.into());
+    }
+
+    // Verify the vesting can be revoked
+    if revocable == 0 {
+        return Err(VestingError::NotRevocable.into());
+    }
+    if status == STATUS_REVOKED {
+        return Err(VestingError::AlreadyRevoked.into());
+    }
+    if status != STATUS_CREATED && status != STATUS_FUNDED {
+        return Err(VestingError::InvalidStatus.into());
+    }
+
+    // Vested tokens go to the beneficiary's ATA, the rest to the admin's
+    ata_amount(beneficiary_ata, &stored_mint, &stored_beneficiary)?;
+    ata_amount(admin_token_account, &stored_mint, &stored_admin)?;
+
+    // Freeze the schedule at the current time
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    // Vested-but-unclaimed tokens go to the beneficiary; the unvested rest and
+    // any surplus sent straight to the vault go back to the admin
+    let vault_amount = ata_amount(vault, &stored_mint, vesting_account.address().as_array())?;
+    let vested = if status == STATUS_FUNDED {
+        let total_released = calculate_released(total_amount, start_time, cliff_time, end_time, &schedule, now);
+        total_released.saturating_sub(released_amount).min(vault_amount)
+    } else {
+        0
+    };
+    let unvested = vault_amount - vested;
+
+    // Build PDA signer seeds
+    let seed_bytes = seed.to_le_bytes();
+    let bump_bytes = [bump];
+    let (seeds, seed_count) = vesting_signer_seeds(
+        &seed_admin, &seed_beneficiary, &stored_mint, &seed_bytes, &bump_bytes, legacy,
+    );
+
+    let decimals = mint_decimals(mint)?;
+
+    // Pay out the vested portion to the beneficiary
+    if vested > 0 {
+        TransferChecked {
+            from: vault,
+            mint,
+            to: beneficiary_ata,
+            authority: vesting_account,
+            amount: vested,
+            decimals,
+        }
+        .invoke_signed(&[Signer::from(&seeds[..seed_count])])?;
+    }
+
+    // Return the unvested remainder to the admin
+    if unvested > 0 {
+        TransferChecked {
+            from: vault,
+            mint,
+            to: admin_token_account,
+            authority: vesting_account,
+            amount: unvested,
+            decimals,
+        }
+        .invoke_signed(&[Signer::from(&seeds[..seed_count])])?;
+    }
+
+    // Update released_amount and record the revocation time
+    let new_released = released_amount + vested;
+    {
+        let mut data = vesting_account.try_borrow_mut()?;
+        data[RELEASED_AMOUNT_OFF..RELEASED_AMOUNT_OFF + 8]
+            .copy_from_slice(&new_released.to_le_bytes());
+        data[REVOKED_AT_OFF..REVOKED_AT_OFF + 8].copy_from_slice(&now.to_le_bytes());
+        data[STATUS_OFF] = STATUS_REVOKED;
+    }
+
+    Event::<VESTING_REVOKED_SIZE>::new(&VESTING_REVOKED_DISC)
+        .push(vesting_account.address().as_ref())
+        .push(&stored_admin)
+        .push(&stored_beneficiary)
+        .push(&stored_mint)
+        .push(&seed.to_le_bytes())
+        .push(&vested.to_le_bytes())
+        .push(&unvested.to_le_bytes())
+        .push(&new_released.to_le_bytes())
+        .push(&now.to_le_bytes())
+        .emit();
+    Ok(())
+}
+
+// ─────────────────────────────────────────────
+// Instruction 4: Close Vesting
+// ─────────────────────────────────────────────
+// Data: empty
+// Accounts: [admin(s,w), mint, vesting_account(w), vault(w), admin_token_account(w), token_program,
+//            config]
+// Rent and surplus go to the current admin, who receives them along with the role.
+
+fn process_close_vesting(
+    program_id: &Address,
+    accounts: &[AccountView],
+    _data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 7 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let admin = &accounts[0];
+    let mint = &accounts[1];
+    let vesting_account = &accounts[2];
+    let vault = &accounts[3];
+    let admin_token_account = &accounts[4];
+    let token_program = &accounts[5];
+    let config = &accounts[6];
+
+    check_config(program_id, config, 0)?;
+    check_token_program(token_program)?;
+
+    // Validate signer
+    if !admin.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Validate ownership, discriminator and layout version
+    check_vesting(program_id, vesting_account)?;
+
+    // Read vesting data (copy to locals, then drop borrow)
+    let (stored_admin, stored_mint, seed, bump, status, legacy, seed_admin, seed_beneficiary) = {
+        let d = vesting_account.try_borrow()?;
+        let mut a = [0u8; 32];
+        a.copy_from_slice(&d[ADMIN_OFF..ADMIN_OFF + 32]);
+        let mut m = [0u8; 32];
+        m.copy_from_slice(&d[MINT_OFF..MINT_OFF + 32]);
+        let sd = u64::from_le_bytes(d[SEED_OFF..SEED_OFF + 8].try_into().unwrap());
+        let bp = d[BUMP_OFF];
+        let ss = d[STATUS_OFF];
+        let lg = d[LEGACY_SEEDS_OFF] != 0;
+        let mut sa = [0u8; 32];
+        sa.copy_from_slice(&d[SEED_ADMIN_OFF..SEED_ADMIN_OFF + 32]);
+        let mut sb = [0u8; 32];
+        sb.copy_from_slice(&d[SEED_BENEFICIARY_OFF..SEED_BENEFICIARY_OFF + 32]);
+        (a, m, sd, bp, ss, lg, sa, sb)
+    };
+
+    // Verify admin (the rent goes to the current admin, which after
+    // accept_admin may not be the original payer)
+    if stored_admin != *admin.address().as_array() {
+        return Err(VestingError::UnauthorizedAdmin.into());
+    }
+
+    // Verify mint
+    if stored_mint != *mint.address().as_array() {
+        return Err(VestingError::MintMismatch.into());
+    }
+
+    // Only fully released (or revoked) vestings can be closed
+    if status != STATUS_COMPLETED && status != STATUS_REVOKED {
+        return Err(VestingError::VestingNotComplete.into());
+    }
+
+    // Nothing is owed to the beneficiary any more, so whatever is left in the
+    // vault is surplus (tokens sent straight to it)
+    let surplus = ata_amount(vault, &stored_mint, vesting_account.address().as_array())?;
+    ata_amount(admin_token_account, &stored_mint, &stored_admin)?;
+
+    // Build PDA signer seeds
+    let seed_bytes = seed.to_le_bytes();
+    let bump_bytes = [bump];
+    let (seeds, seed_count) = vesting_signer_seeds(
+        &seed_admin, &seed_beneficiary, &stored_mint, &seed_bytes, &bump_bytes, legacy,
+    );
+
+    // Return any surplus to the admin
+    if surplus > 0 {
+        let decimals = mint_decimals(mint)?;
+        TransferChecked {
+            from: vault,
+            mint,
+            to: admin_token_account,
+            authority: vesting_account,
+            amount: surplus,
+            decimals,
+        }
+        .invoke_signed(&[Signer::from(&seeds[..seed_count])])?;
+    }
+
+    // Close the vault, returning its rent to the admin
+    CloseAccount {
+        account: vault,
+        destination: admin,
+        authority: vesting_account,
+    }
+    .invoke_signed(&[Signer::from(&seeds[..seed_count])])?;
+
+    // Close the vesting account, returning its rent to the admin
+    admin.set_lamports(admin.lamports() + vesting_account.lamports());
+    vesting_account.set_lamports(0);
+    vesting_account.close()?;
+
+    Event::<VESTING_CLOSED_SIZE>::new(&VESTING_CLOSED_DISC)
+        .push(vesting_account.address().as_ref())
+        .push(&stored_admin)
+        .push(&stored_mint)
+        .push(&seed.to_le_bytes())
+        .emit();
+    Ok(())
+}
+
+// ─────────────────────────────────────────────
+// Instruction 5: Migrate Vesting
+// ─────────────────────────────────────────────
+// Upgrades a headerless account to the current layout: prepends the Anchor
+// discriminator and sets the layout version. Accounts created before
+// admin-bound seeds are also filled in; they keep their address and
+// LEGACY_SEEDS_OFF records how to sign for them.
+// Data: empty
+// Accounts: [payer(s,w), vesting_account(w), vault(w), system_program, config]
+
+fn process_migrate_vesting(
+    program_id: &Address,
+    accounts: &[AccountView],
+    _data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 5 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let payer = &accounts[0];
+    let vesting_account = &accounts[1];
+    let vault = &accounts[2];
+    let _system_program = &accounts[3];
+    let config = &accounts[4];
+
+    check_config(program_id, config, 0)?;
+
+    // Validate signer
+    if !payer.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Validate ownership
+    if !vesting_account.owned_by(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Only the headerless layouts can be migrated
+    let old_len = vesting_account.data_len();
+    if old_len != LEGACY_VESTING_SIZE && old_len != HEADERLESS_VESTING_SIZE {
+        return Err(VestingError::InvalidLegacyAccount.into());
+    }
+
+    // Grow the account, topping up rent from the payer
+    let rent = Rent::get()?;
+    #[allow(deprecated)]
+    let shortfall = rent
+        .minimum_balance(VESTING_SIZE)
+        .saturating_sub(vesting_account.lamports());
+    if shortfall > 0 {
+        Transfer {
+            from: payer,
+            to: vesting_account,
+            lamports: shortfall,
+        }
+        .invoke()?;
+    }
+    vesting_account.resize(VESTING_SIZE)?;
+
+    // Move the old fields behind the header
+    {
+        let mut data = vesting_account.try_borrow_mut()?;
+        data.copy_within(0..old_len, ADMIN_OFF);
+        data[DISC_OFF..DISC_OFF + 8].copy_from_slice(&VESTING_DISC);
+        data[LAYOUT_VERSION_OFF] = VESTING_LAYOUT_VERSION;
+    }
+
+    // The headerless current layout needs nothing else
+    if old_len == HEADERLESS_VESTING_SIZE {
+        return Ok(());
+    }
+
+    // Read legacy vesting data (copy to locals, then drop borrow)
+    let (stored_beneficiary, stored_mint, total_amount, released_amount, seed, bump) = {
+        let d = vesting_account.try_borrow()?;
+        let mut b = [0u8; 32];
+        b.copy_from_slice(&d[BENEFICIARY_OFF..BENEFICIARY_OFF + 32]);
+        let mut m = [0u8; 32];
+        m.copy_from_slice(&d[MINT_OFF..MINT_OFF + 32]);
+        let ta = u64::from_le_bytes(d[TOTAL_AMOUNT_OFF..TOTAL_AMOUNT_OFF + 8].try_into().unwrap());
+        let ra = u64::from_le_bytes(d[RELEASED_AMOUNT_OFF..RELEASED_AMOUNT_OFF + 8].try_into().unwrap());
+        let sd = u64::from_le_bytes(d[SEED_OFF..SEED_OFF + 8].try_into().unwrap());
+        let bp = d[BUMP_OFF];
+        (b, m, ta, ra, sd, bp)
+    };
+
+    // The address must be the legacy PDA, so the stored bump signs for it
+    let seed_bytes = seed.to_le_bytes();
+    let bump_bytes = [bump];
+    let expected = Address::create_program_address(
+        &[b"vesting", &stored_beneficiary, &stored_mint, &seed_bytes, &bump_bytes],
+        program_id,
+    )
+    .map_err(|_| ProgramError::from(VestingError::InvalidLegacyAccount))?;
+    if expected != *vesting_account.address() {
+        return Err(VestingError::InvalidLegacyAccount.into());
+    }
+
+    // The vault must be this vesting's ATA for the stored mint
+    let vault_amount = ata_amount(vault, &stored_mint, vesting_account.address().as_array())?;
+
+    // Legacy accounts tracked funding only through the vault balance; only a
+    // vault covering the whole outstanding amount counts as funded
+    let outstanding = total_amount - released_amount;
+    let status = if outstanding == 0 {
+        STATUS_COMPLETED
+    } else if vault_amount >= outstanding {
+        STATUS_FUNDED
+    } else {
+        STATUS_CREATED
+    };
+
+    {
+        let mut data = vesting_account.try_borrow_mut()?;
+        data[REVOCABLE_OFF] = 0;
+        data[REVOKED_AT_OFF..REVOKED_AT_OFF + 8].copy_from_slice(&0i64.to_le_bytes());
+        data[STATUS_OFF] = status;
+        data[LEGACY_SEEDS_OFF] = 1;
+        data[SCHEDULE_KIND_OFF] = SCHEDULE_LINEAR;
+        data[PERIOD_SECONDS_OFF..PERIOD_SECONDS_OFF + 8].copy_from_slice(&0u64.to_le_bytes());
+        // No custom table, no cliff unlock, accrual from start_time, no crank,
+        // no claim delegate, no pending admin, not transferable
+        data[SCHEDULE_LEN_OFF..LAYOUT_VERSION_OFF].fill(0);
+        data.copy_within(ADMIN_OFF..ADMIN_OFF + 32, SEED_ADMIN_OFF);
+        data.copy_within(BENEFICIARY_OFF..BENEFICIARY_OFF + 32, SEED_BENEFICIARY_OFF);
+    }
+    Ok(())
+}
+
+// ─────────────────────────────────────────────
+// Instruction 6: Create And Fund Vesting
+// ─────────────────────────────────────────────
+// Create + Deposit in one instruction, so the vesting is never visible unfunded.
+// The vault ATA is created here if missing, as in Create Vesting.
+// Data: same as Create Vesting
+// Accounts: [admin(s,w), beneficiary, mint, vesting_account(w), vault(w),
+//            admin_token_account(w), system_program, token_program,
+//            associated_token_program, config, treasury(w), treasury_token_account(w)]
+// The treasury accounts are only required when a fee is charged; pass the
+// treasury wallet at index 10 whenever index 11 is needed.
+
+fn process_create_and_fund_vesting(
+    program_id: &Address,
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 10 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let admin = &accounts[0];
+    let beneficiary = &accounts[1];
+    let mint = &accounts[2];
+    let vesting_account = &accounts[3];
+    let vault = &accounts[4];
+    let admin_token_account = &accounts[5];
+    let system_program = &accounts[6];
+    let token_program = &accounts[7];
+    let associated_token_program = &accounts[8];
+    let config = &accounts[9];
+    let treasury = accounts.get(10);
+    let treasury_token_account = accounts.get(11);
+
+    check_config(program_id, config, PAUSE_CREATE | PAUSE_DEPOSIT)?;
+    check_token_program(token_program)?;
+    check_associated_token_program(associated_token_program)?;
+    ata_amount(admin_token_account, mint.address().as_array(), admin.address().as_array())?;
+
+    let creation_fee = collect_creation_fee(config, admin, treasury)?;
+
+    create_vesting(
+        program_id,
+        admin,
+        beneficiary.address(),
+        mint,
+        vesting_account,
+        data,
+        creation_fee,
+    )?;
+    create_vault(admin, vault, vesting_account, mint, system_program, token_program)?;
+
+    deposit(
+        program_id,
+        admin,
+        mint,
+        vesting_account,
+        vault,
+        admin_token_account,
+        config,
+        treasury_token_account,
+    )
+}
+
+// ─────────────────────────────────────────────
+// Instruction 7: Claim To
+// ─────────────────────────────────────────────
+// Claim into any token account for the vesting mint (multisig vault,
+// exchange deposit, ...), still signed by the beneficiary.
+// Data: empty (claim everything claimable) or amount(8)
+// Accounts: [beneficiary(s), mint, vesting_account(w), vault(w),
+//            destination_token_account(w), token_program, config]
+
+fn process_claim_to(
+    program_id: &Address,
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 7 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let beneficiary = &accounts[0];
+    let mint = &accounts[1];
+    let vesting_account = &accounts[2];
+    let vault = &accounts[3];
+    let destination = &accounts[4];
+    let token_program = &accounts[5];
+    let config = &accounts[6];
+
+    check_config(program_id, config, PAUSE_CLAIM)?;
+    check_token_program(token_program)?;
+
+    claim(program_id, beneficiary, mint, vesting_account, vault, destination, data, ClaimMode::ClaimTo)
+}
+
+// ─────────────────────────────────────────────
+// Instruction 8: Crank Claim
+// ─────────────────────────────────────────────
+// Data: empty
+// Accounts: [cranker(s,w), beneficiary, mint, vesting_account(w), vault(w),
+//            beneficiary_token_account(w), system_program, token_program,
+//            associated_token_program, config]
+// Anyone may push everything claimable to the beneficiary's canonical ATA on
+// vestings created with allow_permissionless_claim. The ATA is created if
+// missing, paid by the cranker (Anchor's `init_if_needed`).
+
+fn process_crank_claim(
+    program_id: &Address,
+    accounts: &[AccountView],
+    _data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 10 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let cranker = &accounts[0];
+    let beneficiary = &accounts[1];
+    let mint = &accounts[2];
+    let vesting_account = &accounts[3];
+    let vault = &accounts[4];
+    let beneficiary_ata = &accounts[5];
+    let system_program = &accounts[6];
+    let token_program = &accounts[7];
+    let associated_token_program = &accounts[8];
+    let config = &accounts[9];
+
+    check_config(program_id, config, PAUSE_CLAIM)?;
+    check_token_program(token_program)?;
+    check_associated_token_program(associated_token_program)?;
+
+    // The ATA is derived from the beneficiary account, so it must be the stored one
+    check_vesting(program_id, vesting_account)?;
+    if vesting_account.try_borrow()?[BENEFICIARY_OFF..BENEFICIARY_OFF + 32]
+        != *beneficiary.address().as_array()
+    {
+        return Err(VestingError::UnauthorizedBeneficiary.into());
+    }
+
+    CreateIdempotent {
+        funding_account: cranker,
+        account: beneficiary_ata,
+        wallet: beneficiary,
+        mint,
+        system_program,
+        token_program,
+    }
+    .invoke()?;
+
+    // The crank always releases the full claimable amount
+    claim(program_id, cranker, mint, vesting_account, vault, beneficiary_ata, &[], ClaimMode::Crank)
+}
+
+// ─────────────────────────────────────────────
+// Instruction 9: Set Claim Delegate
+// ─────────────────────────────────────────────
+// Data: claim_delegate(32), all zeros to clear
+// Accounts: [beneficiary(s), vesting_account(w), config]
+
+fn process_set_claim_delegate(
+    program_id: &Address,
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 3 || data.len() < 32 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let beneficiary = &accounts[0];
+    let vesting_account = &accounts[1];
+    let config = &accounts[2];
+
+    check_config(program_id, config, 0)?;
+
+    if !beneficiary.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    check_vesting(program_id, vesting_account)?;
+
+    {
+        let mut d = vesting_account.try_borrow_mut()?;
+        if d[BENEFICIARY_OFF..BENEFICIARY_OFF + 32] != *beneficiary.address().as_array() {
+            return Err(VestingError::UnauthorizedBeneficiary.into());
+        }
+        d[CLAIM_DELEGATE_OFF..CLAIM_DELEGATE_OFF + 32].copy_from_slice(&data[..32]);
+    }
+
+    Event::<CLAIM_DELEGATE_UPDATED_SIZE>::new(&CLAIM_DELEGATE_UPDATED_DISC)
+        .push(vesting_account.address().as_ref())
+        .push(beneficiary.address().as_ref())
+        .push(&data[..32])
+        .emit();
+    Ok(())
+}
+
+// ─────────────────────────────────────────────
+// Instruction 10: Propose Admin
+// ─────────────────────────────────────────────
+// Data: new_admin(32), all zeros to cancel a pending proposal
+// Accounts: [admin(s), vesting_account(w), config]
+
+fn process_propose_admin(
+    program_id: &Address,
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 3 || data.len() < 32 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let admin = &accounts[0];
+    let vesting_account = &accounts[1];
+    let config = &accounts[2];
+
+    check_config(program_id, config, 0)?;
+
+    if !admin.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    check_vesting(program_id, vesting_account)?;
+
+    {
+        let mut d = vesting_account.try_borrow_mut()?;
+        if d[ADMIN_OFF..ADMIN_OFF + 32] != *admin.address().as_array() {
+            return Err(VestingError::UnauthorizedAdmin.into());
+        }
+        d[PENDING_ADMIN_OFF..PENDING_ADMIN_OFF + 32].copy_from_slice(&data[..32]);
+    }
+
+    Event::<ADMIN_PROPOSED_SIZE>::new(&ADMIN_PROPOSED_DISC)
+        .push(vesting_account.address().as_ref())
+        .push(admin.address().as_ref())
+        .push(&data[..32])
+        .emit();
+    Ok(())
+}
+
+// ─────────────────────────────────────────────
+// Instruction 11: Accept Admin
+// ─────────────────────────────────────────────
+// Data: empty
+// Accounts: [new_admin(s), vesting_account(w), config]
+
+fn process_accept_admin(
+    program_id: &Address,
+    accounts: &[AccountView],
+    _data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 3 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let new_admin = &accounts[0];
+    let vesting_account = &accounts[1];
+    let config = &accounts[2];
+
+    check_config(program_id, config, 0)?;
+
+    if !new_admin.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    check_vesting(program_id, vesting_account)?;
+
+    let previous_admin = {
+        let mut d = vesting_account.try_borrow_mut()?;
+        let pending = &d[PENDING_ADMIN_OFF..PENDING_ADMIN_OFF + 32];
+        if pending == [0u8; 32] || pending != new_admin.address().as_ref() {
+            return Err(VestingError::NotPendingAdmin.into());
+        }
+        let mut previous = [0u8; 32];
+        previous.copy_from_slice(&d[ADMIN_OFF..ADMIN_OFF + 32]);
+        d.copy_within(PENDING_ADMIN_OFF..PENDING_ADMIN_OFF + 32, ADMIN_OFF);
+        d[PENDING_ADMIN_OFF..PENDING_ADMIN_OFF + 32].fill(0);
+        previous
+    };
+
+    Event::<ADMIN_TRANSFERRED_SIZE>::new(&ADMIN_TRANSFERRED_DISC)
+        .push(vesting_account.address().as_ref())
+        .push(&previous_admin)
+        .push(new_admin.address().as_ref())
+        .emit();
+    Ok(())
+}
+
+// ─────────────────────────────────────────────
+// Instruction 12: Transfer Beneficiary
+// ─────────────────────────────────────────────
+// Data: new_beneficiary(32)
+// Accounts: [beneficiary(s), vesting_account(w), config, admin(s, optional)]
+// Needs the transferable flag or the admin's co-signature. The PDA keeps
+// signing with seed_beneficiary, so the vesting address does not change.
+
+fn process_transfer_beneficiary(
+    program_id: &Address,
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 3 || data.len() < 32 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let beneficiary = &accounts[0];
+    let vesting_account = &accounts[1];
+    let config = &accounts[2];
+
+    check_config(program_id, config, 0)?;
+    let admin = accounts.get(3);
+
+    if !beneficiary.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    check_vesting(program_id, vesting_account)?;
+
+    let admin_cosigned = {
+        let mut d = vesting_account.try_borrow_mut()?;
+        if d[BENEFICIARY_OFF..BENEFICIARY_OFF + 32] != *beneficiary.address().as_array() {
+            return Err(VestingError::UnauthorizedBeneficiary.into());
+        }
+        let admin_cosigned = match admin {
+            Some(admin) => {
+                if !admin.is_signer() {
+                    return Err(ProgramError::MissingRequiredSignature);
+                }
+                if d[ADMIN_OFF..ADMIN_OFF + 32] != *admin.address().as_array() {
+                    return Err(VestingError::UnauthorizedAdmin.into());
+                }
+                true
+            }
+            None => false,
+        };
+        if d[TRANSFERABLE_OFF] == 0 && !admin_cosigned {
+            return Err(VestingError::NotTransferable.into());
+        }
+
+        d[BENEFICIARY_OFF..BENEFICIARY_OFF + 32].copy_from_slice(&data[..32]);
+        // A delegate chosen by the previous beneficiary does not carry over
+        d[CLAIM_DELEGATE_OFF..CLAIM_DELEGATE_OFF + 32].fill(0);
+        admin_cosigned
+    };
+
+    Event::<BENEFICIARY_TRANSFERRED_SIZE>::new(&BENEFICIARY_TRANSFERRED_DISC)
+        .push(vesting_account.address().as_ref())
+        .push(beneficiary.address().as_ref())
+        .push(&data[..32])
+        .push(&[admin_cosigned as u8])
+        .emit();
+    Ok(())
+}
+
+// ─────────────────────────────────────────────
+// Instruction 13: Initialize Config
+// ─────────────────────────────────────────────
+// Data: paused(1) + pause_flags(1) + bump(1) + fee schedule (see write_fee_schedule)
+// Accounts: [authority(s,w), config(w), program_data, system_program]
+// Only the program upgrade authority may create the singleton config.
+
+fn process_initialize_config(
+    program_id: &Address,
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 4 || data.len() < 3 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let authority = &accounts[0];
+    let config = &accounts[1];
+    let program_data = &accounts[2];
+    let _system_program = &accounts[3];
+
+    check_upgrade_authority(program_id, authority, program_data)?;
+
+    let paused = data[0];
+    let pause_flags = data[1];
+    let bump = data[2];
+    if paused > 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Only the canonical bump is accepted, so there is exactly one config
+    // (`verify_config_address` trusts the stored bump afterwards)
+    let (expected, canonical_bump) = Address::find_program_address(&[b"config"], program_id);
+    if bump != canonical_bump || expected != *config.address() {
+        return Err(ProgramError::Custom(ERROR_CONSTRAINT_SEEDS));
+    }
+
+    let bump_bytes = [bump];
+    let seeds = [Seed::from(b"config" as &[u8]), Seed::from(&bump_bytes as &[u8])];
+    create_pda_account(authority, config, CONFIG_SIZE, program_id, &seeds)?;
+
+    {
+        let mut d = config.try_borrow_mut()?;
+        d[CONFIG_PAUSED_OFF] = paused;
+        d[CONFIG_PAUSE_FLAGS_OFF] = pause_flags;
+        d[CONFIG_BUMP_OFF] = bump;
+        write_fee_schedule(&mut d, &data[3..])?;
+    }
+
+    emit_config_updated(authority, config)
+}
+
+// ─────────────────────────────────────────────
+// Instruction 14: Update Config
+// ─────────────────────────────────────────────
+// Data: paused(1) + pause_flags(1) + fee schedule (see write_fee_schedule)
+// Accounts: [authority(s), config(w), program_data]
+
+fn process_update_config(
+    program_id: &Address,
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 3 || data.len() < 2 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let authority = &accounts[0];
+    let config = &accounts[1];
+    let program_data = &accounts[2];
+
+    check_upgrade_authority(program_id, authority, program_data)?;
+    verify_config_address(program_id, config)?;
+
+    let paused = data[0];
+    let pause_flags = data[1];
+    if paused > 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    {
+        let mut d = config.try_borrow_mut()?;
+        d[CONFIG_PAUSED_OFF] = paused;
+        d[CONFIG_PAUSE_FLAGS_OFF] = pause_flags;
+        write_fee_schedule(&mut d, &data[2..])?;
+    }
+
+    emit_config_updated(authority, config)
+}
+
+/// Parse a fee schedule and write it into config data.
+/// Data: treasury(32) + creation_fee_lamports(8) + deposit_fee_bps(2)
+///       + exempt_count(1) + exempt_count x admin(32)
+fn write_fee_schedule(d: &mut [u8], data: &[u8]) -> ProgramResult {
+    if data.len() < 43 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let deposit_fee_bps = u16::from_le_bytes(data[40..42].try_into().unwrap());
+    let exempt_count = data[42] as usize;
+    if data.len() < 43 + exempt_count * 32 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if exempt_count > MAX_FEE_EXEMPT || deposit_fee_bps > MAX_FEE_BPS {
+        return Err(VestingError::InvalidFeeConfig.into());
+    }
+
+    // treasury + creation_fee_lamports + deposit_fee_bps are stored as sent
+    d[CONFIG_TREASURY_OFF..CONFIG_FEE_EXEMPT_LEN_OFF].copy_from_slice(&data[..42]);
+    d[CONFIG_FEE_EXEMPT_LEN_OFF] = exempt_count as u8;
+    d[CONFIG_FEE_EXEMPT_OFF..CONFIG_SIZE].fill(0);
+    d[CONFIG_FEE_EXEMPT_OFF..CONFIG_FEE_EXEMPT_OFF + exempt_count * 32]
+        .copy_from_slice(&data[43..43 + exempt_count * 32]);
+    Ok(())
+}
+
+fn emit_config_updated(authority: &AccountView, config: &AccountView) -> ProgramResult {
+    let d = config.try_borrow()?;
+    Event::<CONFIG_UPDATED_SIZE>::new(&CONFIG_UPDATED_DISC)
+        .push(authority.address().as_ref())
+        .push(&[d[CONFIG_PAUSED_OFF]])
+        .push(&[d[CONFIG_PAUSE_FLAGS_OFF]])
+        .push(&d[CONFIG_TREASURY_OFF..CONFIG_TREASURY_OFF + 32])
+        .push(&d[CONFIG_CREATION_FEE_OFF..CONFIG_CREATION_FEE_OFF + 8])
+        .push(&d[CONFIG_DEPOSIT_FEE_BPS_OFF..CONFIG_DEPOSIT_FEE_BPS_OFF + 2])
+        .push(&[d[CONFIG_FEE_EXEMPT_LEN_OFF]])
+        .emit();
+    Ok(())
+}
+
+// ─────────────────────────────────────────────
+// Instruction 15: Create Vesting Batch
+// ─────────────────────────────────────────────
+// Create and fund many vestings of one mint from one admin token account.
+// Data: entry_count(1) + entry_count x (beneficiary(32) + Create Vesting data)
+// Accounts: [admin(s,w), mint, admin_token_account(w), system_program, token_program,
+//            associated_token_program, config, treasury(w), treasury_token_account(w),
+//            entry_count x (vesting_account(w), vault(w))]
+// The treasury accounts are always present but only checked when a fee is charged.
+// Vaults are created if missing, as for Create and Fund.
+
+fn process_create_vesting_batch(
+    program_id: &Address,
+    accounts: &[AccountView],
+    data: &[u8],
+) -> ProgramResult {
+    if accounts.len() < 9 || data.is_empty() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let admin = &accounts[0];
+    let mint = &accounts[1];
+    let admin_token_account = &accounts[2];
+    let system_program = &accounts[3];
+    let token_program = &accounts[4];
+    let associated_token_program = &accounts[5];
+    let config = &accounts[6];
+    let treasury = &accounts[7];
+    let treasury_token_account = &accounts[8];
+    let entry_accounts = &accounts[9..];
+
+    check_config(program_id, config, PAUSE_CREATE | PAUSE_DEPOSIT)?;
+    check_token_program(token_program)?;
+    check_associated_token_program(associated_token_program)?;
+
+    let entry_count = data[0] as usize;
+    if entry_count == 0 || entry_accounts.len() != entry_count * 2 {
+        return Err(VestingError::InvalidBatchAccounts.into());
+    }
+
+    // Every entry is funded from the same admin ATA, so derive it once
+    ata_amount(admin_token_account, mint.address().as_array(), admin.address().as_array())?;
+
+    let mut rest = &data[1..];
+    for pair in entry_accounts.chunks_exact(2) {
+        let (vesting_account, vault) = (&pair[0], &pair[1]);
+
+        // beneficiary(32) + fixed create data(63) + point_count x 16
+        if rest.len() < 32 + 63 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let entry_len = 32 + 63 + rest[32 + 62] as usize * SCHEDULE_POINT_SIZE;
+        if rest.len() < entry_len {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let beneficiary = Address::new_from_array(rest[..32].try_into().unwrap());
+        let create_data = &rest[32..entry_len];
+        rest = &rest[entry_len..];
+
+        let creation_fee = collect_creation_fee(config, admin, Some(treasury))?;
+        create_vesting(
+            program_id,
+            admin,
+            &beneficiary,
+            mint,
+            vesting_account,
+            create_data,
+            creation_fee,
+        )?;
+        create_vault(admin, vault, vesting_account, mint, system_program, token_program)?;
+        deposit(
+            program_id,
+            admin,
+            mint,
+            vesting_account,
+            vault,
+            admin_token_account,
+            config,
+            Some(treasury_token_account),
+        )?;
+    }
+    Ok(())
+}
+
+// ─────────────────────────────────────────────
+// Helpers
+// ─────────────────────────────────────────────
+
+/// Every token CPI here targets the SPL Token program, so it is the only one accepted
+fn check_token_program(token_program: &AccountView) -> ProgramResult {
+    if *token_program.address() != pinocchio_token::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Ok(())
+}
+
+/// Create `account` as a `space`-byte PDA owned by `owner`, paid by `payer`.
+/// Like Anchor's `init`, an address that already holds lamports (sent by anyone
+/// to block the create) is topped up, allocated and assigned instead.
+fn create_pda_account(
+    payer: &AccountView,
+    account: &AccountView,
+    space: usize,
+    owner: &Address,
+    seeds: &[Seed],
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    #[allow(deprecated)]
+    let lamports = rent.minimum_balance(space);
+
+    let current = account.lamports();
+    if current == 0 {
+        return CreateAccount {
+            from: payer,
+            to: account,
+            lamports,
+            space: space as u64,
+            owner,
+        }
+        .invoke_signed(&[Signer::from(seeds)]);
+    }
+
+    if current < lamports {
+        Transfer {
+            from: payer,
+            to: account,
+            lamports: lamports - current,
+        }
+        .invoke()?;
+    }
+    Allocate {
+        account,
+        space: space as u64,
+    }
+    .invoke_signed(&[Signer::from(seeds)])?;
+    Assign { account, owner }.invoke_signed(&[Signer::from(seeds)])
+}
+
+/// Create `vault` as `vesting_account`'s ATA for `mint`, paid by `admin`. Idempotent
+/// (Anchor's `init_if_needed`), so a vault created ahead of time by anyone cannot
+/// block the grant; the ATA program rejects any other address or an existing
+/// account with another owner or mint.
+fn create_vault(
+    admin: &AccountView,
+    vault: &AccountView,
+    vesting_account: &AccountView,
+    mint: &AccountView,
+    system_program: &AccountView,
+    token_program: &AccountView,
+) -> ProgramResult {
+    CreateIdempotent {
+        funding_account: admin,
+        account: vault,
+        wallet: vesting_account,
+        mint,
+        system_program,
+        token_program,
+    }
+    .invoke()
+}
+
+/// Ensure `associated_token_program` is the Associated Token Account program
+fn check_associated_token_program(associated_token_program: &AccountView) -> ProgramResult {
+    if *associated_token_program.address() != ATA_PROGRAM_ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Ok(())
+}
+
+/// Decimals of `mint`, which must be an SPL Token mint
+fn mint_decimals(mint: &AccountView) -> Result<u8, ProgramError> {
+    if !mint.owned_by(&pinocchio_token::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let d = mint.try_borrow()?;
+    if d.len() != MINT_SIZE {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(d[MINT_DECIMALS_OFF])
+}
+
+/// Balance of `token_account`, which must be a writable SPL Token account for
+/// `mint`, owned by `owner` when given (Anchor's `token::*` constraints)
+fn token_account_amount(
+    token_account: &AccountView,
+    mint: &[u8; 32],
+    owner: Option<&[u8; 32]>,
+) -> Result<u64, ProgramError> {
+    if !token_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if !token_account.owned_by(&pinocchio_token::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let d = token_account.try_borrow()?;
+    if d.len() != TOKEN_ACCOUNT_SIZE
+        || d[TOKEN_MINT_OFF..TOKEN_MINT_OFF + 32] != *mint
+        || owner.is_some_and(|owner| d[TOKEN_OWNER_OFF..TOKEN_OWNER_OFF + 32] != *owner)
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(u64::from_le_bytes(d[TOKEN_AMOUNT_OFF..TOKEN_AMOUNT_OFF + 8].try_into().unwrap()))
+}
+
+/// Balance of `token_account`, which must also be `owner`'s canonical ATA for
+/// `mint` (Anchor's `associated_token::*` constraints)
+fn ata_amount(
+    token_account: &AccountView,
+    mint: &[u8; 32],
+    owner: &[u8; 32],
+) -> Result<u64, ProgramError> {
+    let (expected, _) = Address::find_program_address(
+        &[owner, pinocchio_token::ID.as_ref(), mint],
+        &ATA_PROGRAM_ID,
+    );
+    if expected != *token_account.address() {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    token_account_amount(token_account, mint, Some(owner))
+}
+
+/// Ensure `vesting_account` is one of this program's vestings in the current layout
+fn check_vesting(program_id: &Address, vesting_account: &AccountView) -> ProgramResult {
+    if !vesting_account.owned_by(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let d = vesting_account.try_borrow()?;
+    if d.len() < DISC_OFF + 8 || d[DISC_OFF..DISC_OFF + 8] != VESTING_DISC {
+        return Err(ProgramError::Custom(ERROR_ACCOUNT_DISCRIMINATOR_MISMATCH));
+    }
+    if d.len() != VESTING_SIZE || d[LAYOUT_VERSION_OFF] != VESTING_LAYOUT_VERSION {
+        return Err(ProgramError::Custom(ERROR_ACCOUNT_DID_NOT_DESERIALIZE));
+    }
+    Ok(())
+}
+
+/// Ensure `config` is this program's ["config"] PDA
+fn verify_config_address(program_id: &Address, config: &AccountView) -> ProgramResult {
+    if !config.owned_by(program_id) || config.data_len() != CONFIG_SIZE {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let bump = config.try_borrow()?[CONFIG_BUMP_OFF];
+    let expected = Address::create_program_address(&[b"config", &[bump]], program_id)
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+    if expected != *config.address() {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(())
+}
+
+/// Fail with `VestingError::ProgramPaused` if the program, or any of `pause_bits`, is paused
+fn check_config(program_id: &Address, config: &AccountView, pause_bits: u8) -> ProgramResult {
+    verify_config_address(program_id, config)?;
+    let d = config.try_borrow()?;
+    if d[CONFIG_PAUSED_OFF] != 0 || d[CONFIG_PAUSE_FLAGS_OFF] & pause_bits != 0 {
+        return Err(VestingError::ProgramPaused.into());
+    }
+    Ok(())
+}
+
+/// Whether `admin` is on the config's fee-exempt (partner) list
+fn is_fee_exempt(config_data: &[u8], admin: &AccountView) -> bool {
+    let len = config_data[CONFIG_FEE_EXEMPT_LEN_OFF] as usize;
+    config_data[CONFIG_FEE_EXEMPT_OFF..CONFIG_FEE_EXEMPT_OFF + len * 32]
+        .chunks_exact(32)
+        .any(|a| a == admin.address().as_ref())
+}
+
+/// Charge the flat SOL creation fee to `admin` unless it is fee-exempt.
+/// Returns the lamports paid.
+fn collect_creation_fee(
+    config: &AccountView,
+    admin: &AccountView,
+    treasury: Option<&AccountView>,
+) -> Result<u64, ProgramError> {
+    let (fee, stored_treasury) = {
+        let d = config.try_borrow()?;
+        let fee = if is_fee_exempt(&d, admin) {
+            0
+        } else {
+            u64::from_le_bytes(
+                d[CONFIG_CREATION_FEE_OFF..CONFIG_CREATION_FEE_OFF + 8].try_into().unwrap(),
+            )
+        };
+        let mut t = [0u8; 32];
+        t.copy_from_slice(&d[CONFIG_TREASURY_OFF..CONFIG_TREASURY_OFF + 32]);
+        (fee, t)
+    };
+    if fee > 0 {
+        let treasury = treasury.ok_or(ProgramError::from(VestingError::MissingTreasuryAccount))?;
+        if stored_treasury != *treasury.address().as_array() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Transfer {
+            from: admin,
+            to: treasury,
+            lamports: fee,
+        }
+        .invoke()?;
+    }
+    Ok(fee)
+}
+
+/// Token fee `admin` pays on top of depositing `amount` (rounded down)
+fn deposit_fee(config: &AccountView, admin: &AccountView, amount: u64) -> Result<u64, ProgramError> {
+    let d = config.try_borrow()?;
+    if is_fee_exempt(&d, admin) {
+        return Ok(0);
+    }
+    let bps = u16::from_le_bytes(
+        d[CONFIG_DEPOSIT_FEE_BPS_OFF..CONFIG_DEPOSIT_FEE_BPS_OFF + 2].try_into().unwrap(),
+    );
+    Ok((amount as u128 * bps as u128 / MAX_FEE_BPS as u128) as u64)
+}
+
+/// Require `authority` to sign and be the upgrade authority recorded in this
+/// program's ProgramData account
+fn check_upgrade_authority(
+    program_id: &Address,
+    authority: &AccountView,
+    program_data: &AccountView,
+) -> ProgramResult {
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !program_data.owned_by(&BPF_LOADER_UPGRADEABLE_ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let (expected, _) =
+        Address::find_program_address(&[program_id.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+    if expected != *program_data.address() {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let d = program_data.try_borrow()?;
+    if d.len() < PROGRAM_DATA_AUTHORITY_OFF + 33
+        || d[PROGRAM_DATA_AUTHORITY_OFF] != 1
+        || d[PROGRAM_DATA_AUTHORITY_OFF + 1..PROGRAM_DATA_AUTHORITY_OFF + 33]
+            != *authority.address().as_array()
+    {
+        return Err(VestingError::UnauthorizedConfigAuthority.into());
+    }
+    Ok(())
+}
+
+/// Fixed-size event payload, laid out exactly like Anchor's `emit!`
+struct Event<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Event<N> {
+    fn new(discriminator: &[u8; 8]) -> Self {
+        let mut buf = [0u8; N];
+        buf[..8].copy_from_slice(discriminator);
+        Self { buf, len: 8 }
+    }
+
+    fn push(mut self, bytes: &[u8]) -> Self {
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        self
+    }
+
+    fn emit(self) {
+        debug_assert_eq!(self.len, N);
+        log_data(&[&self.buf]);
+    }
+}
+
+/// `sol_log_data` wrapper (no-op off-chain)
+fn log_data(data: &[&[u8]]) {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        solana_define_syscall::definitions::sol_log_data(
+            data as *const _ as *const u8,
+            data.len() as u64,
+        );
+    }
+    #[cfg(not(target_os = "solana"))]
+    let _ = data;
+}
+
+/// PDA signer seeds for a vesting account, returned with the number in use.
+/// Current seeds: ["vesting", seed_admin, seed_beneficiary, mint, seed, bump],
+/// i.e. the admin and beneficiary at creation (unchanged by later transfers);
+/// legacy seeds (migrated accounts): ["vesting", seed_beneficiary, mint, seed, bump].
+fn vesting_signer_seeds<'a>(
+    admin: &'a [u8; 32],
+    beneficiary: &'a [u8; 32],
+    mint: &'a [u8; 32],
+    seed: &'a [u8; 8],
+    bump: &'a [u8; 1],
+    legacy: bool,
+) -> ([Seed<'a>; 6], usize) {
+    if legacy {
+        let seeds = [
+            Seed::from(b"vesting" as &[u8]),
+            Seed::from(beneficiary as &[u8]),
+            Seed::from(mint as &[u8]),
+            Seed::from(seed as &[u8]),
+            Seed::from(bump as &[u8]),
+            Seed::from(&[] as &[u8]),
+        ];
+        (seeds, 5)
+    } else {
+        let seeds = [
+            Seed::from(b"vesting" as &[u8]),
+            Seed::from(admin as &[u8]),
+            Seed::from(beneficiary as &[u8]),
+            Seed::from(mint as &[u8]),
+            Seed::from(seed as &[u8]),
+            Seed::from(bump as &[u8]),
+        ];
+        (seeds, 6)
+    }
+}
+
+/// Release curve of a vesting account (copied out of the account data)
+struct Schedule {
+    kind: u8,
+    period_seconds: u64,
+    len: usize,
+    points: [(i64, u64); MAX_SCHEDULE_POINTS],
+    cliff_amount: u64,
+    accrue_from_cliff: bool,
+}
+
+impl Schedule {
+    fn read(d: &[u8]) -> Self {
+        let len = (d[SCHEDULE_LEN_OFF] as usize).min(MAX_SCHEDULE_POINTS);
+        let mut points = [(0i64, 0u64); MAX_SCHEDULE_POINTS];
+        for (i, point) in points.iter_mut().enumerate().take(len) {
+            *point = read_point(&d[SCHEDULE_POINTS_OFF + i * SCHEDULE_POINT_SIZE..]);
+        }
+        Self {
+            kind: d[SCHEDULE_KIND_OFF],
+            period_seconds: u64::from_le_bytes(
+                d[PERIOD_SECONDS_OFF..PERIOD_SECONDS_OFF + 8].try_into().unwrap(),
+            ),
+            len,
+            points,
+            cliff_amount: u64::from_le_bytes(
+                d[CLIFF_AMOUNT_OFF..CLIFF_AMOUNT_OFF + 8].try_into().unwrap(),
+            ),
+            accrue_from_cliff: d[ACCRUE_FROM_CLIFF_OFF] != 0,
+        }
+    }
+}
+
+/// (timestamp, cumulative amount) at the start of `d`
+fn read_point(d: &[u8]) -> (i64, u64) {
+    (
+        i64::from_le_bytes(d[0..8].try_into().unwrap()),
+        u64::from_le_bytes(d[8..16].try_into().unwrap()),
+    )
+}
+
+/// Custom tables must be non-decreasing in time and amount, start no earlier
+/// than start_time, and finish exactly at (end_time, total_amount)
+fn schedule_points_valid(points: &[u8], start_time: i64, end_time: i64, total_amount: u64) -> bool {
+    if points.is_empty() {
+        return false;
+    }
+    let mut prev = (start_time, 0u64);
+    for chunk in points.chunks_exact(SCHEDULE_POINT_SIZE) {
+        let point = read_point(chunk);
+        if point.0 < prev.0 || point.1 < prev.1 {
+            return false;
+        }
+        prev = point;
+    }
+    prev == (end_time, total_amount)
+}
+
+/// Mirrors Anchor's `VestingAccount::calculate_released`
+fn calculate_released(
+    total_amount: u64,
+    start_time: i64,
+    cliff_time: i64,
+    end_time: i64,
+    schedule: &Schedule,
+    now: i64,
+) -> u64 {
+    if now < cliff_time {
+        return 0;
+    }
+    if now >= end_time {
+        return total_amount;
+    }
+    // cliff_amount unlocks at the cliff; the rest accrues from the accrual start
+    let accrual_start = if schedule.accrue_from_cliff { cliff_time } else { start_time };
+    let elapsed = (now - accrual_start) as u128;
+    let duration = (end_time - accrual_start) as u128;
+    let accruing = (total_amount - schedule.cliff_amount) as u128;
+    match schedule.kind {
+        SCHEDULE_PERIODIC => {
+            // Equal steps per completed period; the remainder lands at end_time
+            let period = schedule.period_seconds as u128;
+            let periods = duration.div_ceil(period);
+            schedule.cliff_amount + ((accruing / periods) * (elapsed / period)) as u64
+        }
+        SCHEDULE_CUSTOM_LINEAR | SCHEDULE_CUSTOM_STEP => {
+            let points = &schedule.points[..schedule.len];
+            // Last point reached by `now`
+            let Some(i) = points.iter().rposition(|p| p.0 <= now) else {
+                return 0;
+            };
+            let (ts, amount) = points[i];
+            match (schedule.kind, points.get(i + 1)) {
+                (SCHEDULE_CUSTOM_LINEAR, Some(&(next_ts, next_amount))) => {
+                    let span = (next_ts - ts) as u128;
+                    let step = (next_amount - amount) as u128;
+                    amount + (step * (now - ts) as u128 / span) as u64
+                }
+                _ => amount,
+            }
+        }
+        _ => schedule.cliff_amount + ((accruing * elapsed) / duration) as u64,
     }
-    let elapsed = (now - start_time) as u128;
-    let duration = (end_time - start_time) as u128;
-    let total = total_amount as u128;
-    ((total * elapsed) / duration) as u64
 }
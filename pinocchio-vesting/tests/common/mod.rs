@@ -0,0 +1,61 @@
+//! Fixtures shared by the Mollusk tests; each test crate uses a subset
+#![allow(dead_code)]
+
+use mollusk_svm::Mollusk;
+use mollusk_svm_programs_token::{associated_token, token};
+use solana_account::Account;
+use solana_pubkey::Pubkey;
+
+pub const PROGRAM_ID: Pubkey = Pubkey::from_str_const("EzRUZpW3CsvnKzEUiF7fAPHyHWsv2D3ERR482BPKHwYk");
+
+pub const CONFIG_SIZE: usize = 302;
+pub const VESTING_SIZE: usize = 474;
+/// Anchor's `ErrorCode::ConstraintSeeds`
+pub const ERROR_CONSTRAINT_SEEDS: u32 = 2006;
+
+pub struct Setup {
+    pub mollusk: Mollusk,
+    pub admin: Pubkey,
+    pub beneficiary: Pubkey,
+    pub mint: Pubkey,
+    /// An arbitrary vesting address, for tests that do not derive the PDA
+    pub vesting: Pubkey,
+    pub config: (Pubkey, Account),
+}
+
+/// The SBF build with the SPL Token and Associated Token programs loaded
+pub fn mollusk() -> Mollusk {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, "pinocchio_vesting");
+    token::add_program(&mut mollusk);
+    associated_token::add_program(&mut mollusk);
+    mollusk
+}
+
+pub fn setup() -> Setup {
+    let mollusk = mollusk();
+    let config = config_account(&mollusk);
+
+    Setup {
+        mollusk,
+        admin: Pubkey::new_unique(),
+        beneficiary: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        vesting: Pubkey::new_unique(),
+        config,
+    }
+}
+
+/// An unpaused, fee-free config PDA
+pub fn config_account(mollusk: &Mollusk) -> (Pubkey, Account) {
+    let (config, config_bump) = Pubkey::find_program_address(&[b"config"], &PROGRAM_ID);
+    let mut data = vec![0u8; CONFIG_SIZE];
+    data[2] = config_bump;
+    let account = Account {
+        lamports: mollusk.sysvars.rent.minimum_balance(CONFIG_SIZE),
+        data,
+        owner: PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    (config, account)
+}
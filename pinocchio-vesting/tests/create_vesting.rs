@@ -0,0 +1,220 @@
+//! Runs against the SBF build: `cargo build-sbf && cargo test`
+
+mod common;
+
+use common::{setup, Setup, ERROR_CONSTRAINT_SEEDS, PROGRAM_ID, VESTING_SIZE};
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use mollusk_svm_programs_token::{associated_token, token};
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+const MINT_SIZE: usize = 82;
+const TOKEN_ACCOUNT_SIZE: usize = 165;
+
+/// An initialized SPL Token mint with no authorities and 6 decimals
+fn mint_account(s: &Setup) -> Account {
+    let mut data = vec![0u8; MINT_SIZE];
+    data[44] = 6; // decimals
+    data[45] = 1; // is_initialized
+    Account {
+        lamports: s.mollusk.sysvars.rent.minimum_balance(MINT_SIZE),
+        data,
+        owner: token::keyed_account().0,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// The vesting's vault: its ATA for the mint
+fn vault_address(s: &Setup, vesting: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[vesting.as_ref(), token::keyed_account().0.as_ref(), s.mint.as_ref()],
+        &associated_token::keyed_account().0,
+    )
+    .0
+}
+
+fn vesting_seeds<'a>(s: &'a Setup, seed: &'a [u8; 8]) -> [&'a [u8]; 5] {
+    [
+        b"vesting",
+        s.admin.as_ref(),
+        s.beneficiary.as_ref(),
+        s.mint.as_ref(),
+        seed,
+    ]
+}
+
+/// Linear schedule, no custom points (see the instruction 0 layout in lib.rs)
+fn create_vesting_data(seed: u64, bump: u8) -> Vec<u8> {
+    let mut data = vec![0u8; 64];
+    data[1..9].copy_from_slice(&seed.to_le_bytes());
+    data[9..17].copy_from_slice(&1_000u64.to_le_bytes());
+    data[17..25].copy_from_slice(&100i64.to_le_bytes());
+    data[25..33].copy_from_slice(&200i64.to_le_bytes());
+    data[33..41].copy_from_slice(&1_000i64.to_le_bytes());
+    data[41] = bump;
+    data
+}
+
+fn create_vesting(s: &Setup, vesting: Pubkey, seed: u64, bump: u8, checks: &[Check]) {
+    create_vesting_over(s, vesting, Account::default(), Account::default(), seed, bump, checks);
+}
+
+/// `create_vesting` with the given prior state of the vesting and vault addresses
+fn create_vesting_over(
+    s: &Setup,
+    vesting: Pubkey,
+    vesting_state: Account,
+    vault_state: Account,
+    seed: u64,
+    bump: u8,
+    checks: &[Check],
+) {
+    let (system_program, system_account) = keyed_account_for_system_program();
+    let (token_program, token_account) = token::keyed_account();
+    let (ata_program, ata_account) = associated_token::keyed_account();
+    let vault = vault_address(s, &vesting);
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &create_vesting_data(seed, bump),
+        vec![
+            AccountMeta::new(s.admin, true),
+            AccountMeta::new_readonly(s.beneficiary, false),
+            AccountMeta::new_readonly(s.mint, false),
+            AccountMeta::new(vesting, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(ata_program, false),
+            AccountMeta::new_readonly(s.config.0, false),
+        ],
+    );
+    let accounts = vec![
+        (s.admin, Account::new(10_000_000_000, 0, &system_program)),
+        (s.beneficiary, Account::default()),
+        (s.mint, mint_account(s)),
+        (vesting, vesting_state),
+        (vault, vault_state),
+        (system_program, system_account),
+        (token_program, token_account),
+        (ata_program, ata_account),
+        s.config.clone(),
+    ];
+    s.mollusk
+        .process_and_validate_instruction(&instruction, &accounts, checks);
+}
+
+#[test]
+fn creates_vesting_at_canonical_pda() {
+    let s = setup();
+    let seed = 1u64;
+    let (vesting, bump) =
+        Pubkey::find_program_address(&vesting_seeds(&s, &seed.to_le_bytes()), &PROGRAM_ID);
+
+    create_vesting(
+        &s,
+        vesting,
+        seed,
+        bump,
+        &[
+            Check::success(),
+            Check::account(&vesting)
+                .owner(&PROGRAM_ID)
+                .space(VESTING_SIZE)
+                .build(),
+            // The vault ATA is created in the same instruction
+            Check::account(&vault_address(&s, &vesting))
+                .owner(&token::keyed_account().0)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn rejects_non_canonical_bump() {
+    let s = setup();
+    let seed = 2u64;
+    let seed_bytes = seed.to_le_bytes();
+    let seeds = vesting_seeds(&s, &seed_bytes);
+    let (_, canonical_bump) = Pubkey::find_program_address(&seeds, &PROGRAM_ID);
+
+    // The next lower bump that still yields an off-curve address
+    let (vesting, bump) = (0..canonical_bump)
+        .rev()
+        .find_map(|bump| {
+            let mut with_bump = seeds.to_vec();
+            let bump_bytes = [bump];
+            with_bump.push(&bump_bytes);
+            Pubkey::create_program_address(&with_bump, &PROGRAM_ID)
+                .ok()
+                .map(|address| (address, bump))
+        })
+        .expect("a non-canonical bump exists");
+
+    create_vesting(
+        &s,
+        vesting,
+        seed,
+        bump,
+        &[Check::err(ProgramError::Custom(ERROR_CONSTRAINT_SEEDS))],
+    );
+}
+
+#[test]
+fn rejects_address_of_another_seed() {
+    let s = setup();
+    let seed = 3u64;
+    let (_, bump) =
+        Pubkey::find_program_address(&vesting_seeds(&s, &seed.to_le_bytes()), &PROGRAM_ID);
+    let (other, _) =
+        Pubkey::find_program_address(&vesting_seeds(&s, &4u64.to_le_bytes()), &PROGRAM_ID);
+
+    create_vesting(
+        &s,
+        other,
+        seed,
+        bump,
+        &[Check::err(ProgramError::Custom(ERROR_CONSTRAINT_SEEDS))],
+    );
+}
+
+#[test]
+fn creates_vesting_over_prefunded_pda_and_existing_vault() {
+    let s = setup();
+    let seed = 5u64;
+    let (vesting, bump) =
+        Pubkey::find_program_address(&vesting_seeds(&s, &seed.to_le_bytes()), &PROGRAM_ID);
+    let (system_program, _) = keyed_account_for_system_program();
+
+    // Someone sent lamports to the PDA and created its vault ATA ahead of the admin
+    let mut vault_data = vec![0u8; TOKEN_ACCOUNT_SIZE];
+    vault_data[..32].copy_from_slice(s.mint.as_ref());
+    vault_data[32..64].copy_from_slice(vesting.as_ref());
+    vault_data[108] = 1; // AccountState::Initialized
+    let vault_state = Account {
+        lamports: s.mollusk.sysvars.rent.minimum_balance(TOKEN_ACCOUNT_SIZE),
+        data: vault_data,
+        owner: token::keyed_account().0,
+        executable: false,
+        rent_epoch: 0,
+    };
+
+    create_vesting_over(
+        &s,
+        vesting,
+        Account::new(1, 0, &system_program),
+        vault_state,
+        seed,
+        bump,
+        &[
+            Check::success(),
+            Check::account(&vesting)
+                .owner(&PROGRAM_ID)
+                .space(VESTING_SIZE)
+                .lamports(s.mollusk.sysvars.rent.minimum_balance(VESTING_SIZE))
+                .build(),
+        ],
+    );
+}
@@ -0,0 +1,96 @@
+//! Runs against the SBF build: `cargo build-sbf && cargo test`
+
+mod common;
+
+use common::{mollusk, CONFIG_SIZE, ERROR_CONSTRAINT_SEEDS, PROGRAM_ID};
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    Pubkey::from_str_const("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Unpaused, fee-free config at `config` with `bump` (see the instruction 13 layout in lib.rs)
+fn initialize_config(config: Pubkey, bump: u8, checks: &[Check]) {
+    let mollusk = mollusk();
+    let (system_program, system_account) = keyed_account_for_system_program();
+    let authority = Pubkey::new_unique();
+
+    // ProgramData: tag(4) + slot(8) + Some(upgrade_authority)
+    let (program_data, _) =
+        Pubkey::find_program_address(&[PROGRAM_ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+    let mut program_data_bytes = vec![0u8; 45];
+    program_data_bytes[0] = 3;
+    program_data_bytes[12] = 1;
+    program_data_bytes[13..45].copy_from_slice(authority.as_ref());
+
+    let mut data = vec![13, 0, 0, bump];
+    data.extend_from_slice(&[0u8; 43]);
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let accounts = vec![
+        (authority, Account::new(10_000_000_000, 0, &system_program)),
+        (config, Account::default()),
+        (
+            program_data,
+            Account {
+                lamports: mollusk.sysvars.rent.minimum_balance(45),
+                data: program_data_bytes,
+                owner: BPF_LOADER_UPGRADEABLE_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        ),
+        (system_program, system_account),
+    ];
+    mollusk.process_and_validate_instruction(&instruction, &accounts, checks);
+}
+
+#[test]
+fn creates_config_at_canonical_pda() {
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], &PROGRAM_ID);
+
+    initialize_config(
+        config,
+        bump,
+        &[
+            Check::success(),
+            Check::account(&config)
+                .owner(&PROGRAM_ID)
+                .space(CONFIG_SIZE)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn rejects_non_canonical_config_bump() {
+    let (_, canonical_bump) = Pubkey::find_program_address(&[b"config"], &PROGRAM_ID);
+
+    // A second "singleton" at the next lower bump that is still off-curve
+    let (config, bump) = (0..canonical_bump)
+        .rev()
+        .find_map(|bump| {
+            Pubkey::create_program_address(&[b"config", &[bump]], &PROGRAM_ID)
+                .ok()
+                .map(|address| (address, bump))
+        })
+        .expect("a non-canonical bump exists");
+
+    initialize_config(
+        config,
+        bump,
+        &[Check::err(ProgramError::Custom(ERROR_CONSTRAINT_SEEDS))],
+    );
+}
@@ -0,0 +1,156 @@
+//! Runs against the SBF build: `cargo build-sbf && cargo test`
+
+mod common;
+
+use common::{setup, Setup, PROGRAM_ID, VESTING_SIZE};
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+const HEADERLESS_VESTING_SIZE: usize = 465;
+const VESTING_DISC: [u8; 8] = [102, 73, 10, 233, 200, 188, 228, 216];
+const VESTING_LAYOUT_VERSION: u8 = 1;
+const BENEFICIARY_OFF: usize = 40;
+const CLAIM_DELEGATE_OFF: usize = 312;
+const LAYOUT_VERSION_OFF: usize = 473;
+/// Anchor's `ErrorCode::AccountDiscriminatorMismatch`
+const ERROR_ACCOUNT_DISCRIMINATOR_MISMATCH: u32 = 3002;
+/// Anchor's `ErrorCode::AccountDidNotDeserialize`
+const ERROR_ACCOUNT_DID_NOT_DESERIALIZE: u32 = 3003;
+
+/// A program-owned vesting account holding `data`
+fn vesting_account(s: &Setup, data: Vec<u8>) -> Account {
+    Account {
+        lamports: s.mollusk.sysvars.rent.minimum_balance(data.len()),
+        data,
+        owner: PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Current-layout vesting data for `s.beneficiary`; every other field zeroed
+fn vesting_data(s: &Setup) -> Vec<u8> {
+    let mut data = vec![0u8; VESTING_SIZE];
+    data[..8].copy_from_slice(&VESTING_DISC);
+    data[BENEFICIARY_OFF..BENEFICIARY_OFF + 32].copy_from_slice(s.beneficiary.as_ref());
+    data[LAYOUT_VERSION_OFF] = VESTING_LAYOUT_VERSION;
+    data
+}
+
+/// `set_claim_delegate` by the beneficiary over a vesting account holding `data`
+fn set_claim_delegate(s: &Setup, data: Vec<u8>, checks: &[Check]) {
+    let mut ix_data = vec![9];
+    ix_data.extend_from_slice(Pubkey::new_unique().as_ref());
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &ix_data,
+        vec![
+            AccountMeta::new_readonly(s.beneficiary, true),
+            AccountMeta::new(s.vesting, false),
+            AccountMeta::new_readonly(s.config.0, false),
+        ],
+    );
+    let accounts = vec![
+        (s.beneficiary, Account::default()),
+        (s.vesting, vesting_account(s, data)),
+        s.config.clone(),
+    ];
+    s.mollusk
+        .process_and_validate_instruction(&instruction, &accounts, checks);
+}
+
+#[test]
+fn accepts_current_layout() {
+    let s = setup();
+    set_claim_delegate(&s, vesting_data(&s), &[Check::success()]);
+}
+
+#[test]
+fn rejects_wrong_discriminator() {
+    let s = setup();
+    let mut data = vesting_data(&s);
+    data[0] ^= 0xff;
+
+    set_claim_delegate(
+        &s,
+        data,
+        &[Check::err(ProgramError::Custom(ERROR_ACCOUNT_DISCRIMINATOR_MISMATCH))],
+    );
+}
+
+#[test]
+fn rejects_unknown_layout_version() {
+    let s = setup();
+    let mut data = vesting_data(&s);
+    data[LAYOUT_VERSION_OFF] = VESTING_LAYOUT_VERSION + 1;
+
+    set_claim_delegate(
+        &s,
+        data,
+        &[Check::err(ProgramError::Custom(ERROR_ACCOUNT_DID_NOT_DESERIALIZE))],
+    );
+}
+
+#[test]
+fn rejects_headerless_account_until_migrated() {
+    let s = setup();
+    let data = vesting_data(&s)[8..LAYOUT_VERSION_OFF].to_vec();
+    assert_eq!(data.len(), HEADERLESS_VESTING_SIZE);
+
+    set_claim_delegate(
+        &s,
+        data,
+        &[Check::err(ProgramError::Custom(ERROR_ACCOUNT_DISCRIMINATOR_MISMATCH))],
+    );
+}
+
+#[test]
+fn migrates_headerless_account() {
+    let s = setup();
+    let (system_program, system_account) = keyed_account_for_system_program();
+    let payer = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+
+    // The current fields without the discriminator and layout version
+    let mut expected = vesting_data(&s);
+    expected[CLAIM_DELEGATE_OFF..CLAIM_DELEGATE_OFF + 32]
+        .copy_from_slice(Pubkey::new_unique().as_ref());
+    let headerless = expected[8..LAYOUT_VERSION_OFF].to_vec();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &[5],
+        vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(s.vesting, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(s.config.0, false),
+        ],
+    );
+    let accounts = vec![
+        (payer, Account::new(10_000_000_000, 0, &system_program)),
+        (s.vesting, vesting_account(&s, headerless)),
+        (vault, Account::default()),
+        (system_program, system_account),
+        s.config.clone(),
+    ];
+    s.mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[
+            Check::success(),
+            // Fields shifted behind the discriminator, version appended, rent topped up
+            Check::account(&s.vesting)
+                .owner(&PROGRAM_ID)
+                .space(VESTING_SIZE)
+                .data(&expected)
+                .lamports(s.mollusk.sysvars.rent.minimum_balance(VESTING_SIZE))
+                .build(),
+        ],
+    );
+}
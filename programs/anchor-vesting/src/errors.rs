@@ -8,10 +8,10 @@ pub enum VestingError {
     #[msg("Invalid amount: total_amount must be greater than 0")]
     InvalidAmount, // 6001
 
-    #[msg("Unauthorized: only admin can deposit")]
+    #[msg("Unauthorized: only the admin can perform this action")]
     UnauthorizedAdmin, // 6002
 
-    #[msg("Unauthorized: only beneficiary can claim")]
+    #[msg("Unauthorized: only beneficiary or its claim delegate can claim")]
     UnauthorizedBeneficiary, // 6003
 
     #[msg("Already funded: vault already contains tokens")]
@@ -31,4 +31,64 @@ pub enum VestingError {
 
     #[msg("Arithmetic overflow")]
     Overflow, // 6009
+
+    #[msg("Not revocable: this vesting was created as irrevocable")]
+    NotRevocable, // 6010
+
+    #[msg("Already revoked: this vesting has been revoked")]
+    AlreadyRevoked, // 6011
+
+    #[msg("Vesting not complete: tokens remain to be released")]
+    VestingNotComplete, // 6012
+
+    #[msg("Invalid status: instruction not allowed in the vesting's current status")]
+    InvalidStatus, // 6013
+
+    #[msg("Invalid legacy account: not a pre-upgrade vesting account")]
+    InvalidLegacyAccount, // 6014
+
+    #[msg("Invalid period: periodic schedules need 0 < period_seconds <= end - accrual start (cliff if accrue_from_cliff, else start), linear ones 0")]
+    InvalidPeriod, // 6015
+
+    #[msg("Invalid schedule: points must be non-decreasing and end at (end_time, total_amount)")]
+    InvalidSchedule, // 6016
+
+    #[msg("Invalid cliff amount: must not exceed total_amount (and must be 0 for custom schedules)")]
+    InvalidCliffAmount, // 6017
+
+    #[msg("Claim amount exceeds the currently claimable balance")]
+    ClaimAmountExceedsClaimable, // 6018
+
+    #[msg("Permissionless claims are not enabled for this vesting")]
+    PermissionlessClaimDisabled, // 6019
+
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin, // 6020
+
+    #[msg("Vesting is not transferable without an admin co-signature")]
+    NotTransferable, // 6021
+
+    #[msg("Program is paused")]
+    ProgramPaused, // 6022
+
+    #[msg("Unauthorized: only the program upgrade authority can manage config")]
+    UnauthorizedConfigAuthority, // 6023
+
+    #[msg("Invalid fee config: fee bps above 10000 or too many fee-exempt admins")]
+    InvalidFeeConfig, // 6024
+
+    #[msg("Treasury account is required when a protocol fee is charged")]
+    MissingTreasuryAccount, // 6025
+
+    #[msg("Batch remaining accounts do not match the entries")]
+    InvalidBatchAccounts, // 6026
+
+    #[msg("Invalid merkle proof, or leaf differs from the one proven on the first claim")]
+    InvalidMerkleProof, // 6027
+
+    #[msg("Not allowed for a vesting represented by an NFT receipt")]
+    NotAllowedWithReceipt, // 6028
+
+    #[msg("Invalid receipt: signer must hold this vesting's NFT receipt")]
+    InvalidReceipt, // 6029
 }
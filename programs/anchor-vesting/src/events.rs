@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ScheduleKind;
+
+// Emitted via `emit!` (sol_log_data: 8-byte discriminator + borsh fields).
+// pinocchio_vesting logs byte-identical payloads, so one decoder serves both.
+
+#[event]
+pub struct VestingCreated {
+    pub vesting: Pubkey,
+    pub admin: Pubkey,
+    pub beneficiary: Pubkey,
+    pub mint: Pubkey,
+    pub seed: u64,
+    pub total_amount: u64,
+    pub start_time: i64,
+    pub cliff_time: i64,
+    pub end_time: i64,
+    pub revocable: bool,
+    pub schedule_kind: ScheduleKind,
+    pub period_seconds: u64,
+    pub cliff_amount: u64,
+    pub accrue_from_cliff: bool,
+    pub allow_permissionless_claim: bool,
+    pub transferable: bool,
+    /// SOL creation fee paid to the treasury (0 when exempt)
+    pub creation_fee_lamports: u64,
+}
+
+#[event]
+pub struct VestingFunded {
+    pub vesting: Pubkey,
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub seed: u64,
+    /// Tokens transferred into the vault by this deposit
+    pub amount: u64,
+    /// Vault balance after the deposit
+    pub vault_balance: u64,
+    /// Protocol fee sent to the treasury on top of `amount` (0 when exempt)
+    pub fee_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensClaimed {
+    pub vesting: Pubkey,
+    pub beneficiary: Pubkey,
+    pub mint: Pubkey,
+    pub seed: u64,
+    /// Tokens transferred to the beneficiary by this claim
+    pub amount: u64,
+    /// Cumulative released_amount after the claim
+    pub released_amount: u64,
+    pub total_amount: u64,
+    /// Vault balance after the claim
+    pub vault_balance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingRevoked {
+    pub vesting: Pubkey,
+    pub admin: Pubkey,
+    pub beneficiary: Pubkey,
+    pub mint: Pubkey,
+    pub seed: u64,
+    /// Vested tokens paid out to the beneficiary on revocation
+    pub vested_amount: u64,
+    /// Unvested tokens returned to the admin
+    pub returned_amount: u64,
+    /// Cumulative released_amount after the revocation
+    pub released_amount: u64,
+    pub revoked_at: i64,
+}
+
+#[event]
+pub struct ClaimDelegateUpdated {
+    pub vesting: Pubkey,
+    pub beneficiary: Pubkey,
+    /// New delegate, `Pubkey::default()` when cleared
+    pub claim_delegate: Pubkey,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub vesting: Pubkey,
+    pub admin: Pubkey,
+    /// Proposed admin, `Pubkey::default()` when the proposal is cancelled
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub vesting: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct BeneficiaryTransferred {
+    pub vesting: Pubkey,
+    pub previous_beneficiary: Pubkey,
+    pub new_beneficiary: Pubkey,
+    /// Whether the admin co-signed the transfer
+    pub admin_cosigned: bool,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub pause_flags: u8,
+    pub treasury: Pubkey,
+    pub creation_fee_lamports: u64,
+    pub deposit_fee_bps: u16,
+    pub fee_exempt_len: u8,
+}
+
+#[event]
+pub struct VestingClosed {
+    pub vesting: Pubkey,
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub seed: u64,
+}
+
+#[event]
+pub struct MerkleVestingCreated {
+    pub merkle_vesting: Pubkey,
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub seed: u64,
+    pub merkle_root: [u8; 32],
+    /// Tokens deposited into the shared vault (sum of all leaves)
+    pub total_amount: u64,
+    /// SOL creation fee paid to the treasury (0 when exempt)
+    pub creation_fee_lamports: u64,
+    /// Protocol fee sent to the treasury on top of `total_amount` (0 when exempt)
+    pub fee_amount: u64,
+    /// When the admin may reclaim unclaimed tokens (0 = never)
+    pub clawback_time: i64,
+}
+
+#[event]
+pub struct MerkleVestingClosed {
+    pub merkle_vesting: Pubkey,
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub seed: u64,
+    /// Unclaimed (and surplus) tokens returned to the admin
+    pub reclaimed_amount: u64,
+}
+
+#[event]
+pub struct MerkleTokensClaimed {
+    pub merkle_vesting: Pubkey,
+    pub beneficiary: Pubkey,
+    pub mint: Pubkey,
+    /// Tokens transferred to the beneficiary by this claim
+    pub amount: u64,
+    /// The beneficiary's cumulative released_amount after the claim
+    pub released_amount: u64,
+    /// The beneficiary's leaf total_amount
+    pub total_amount: u64,
+    /// Vault balance after the claim
+    pub vault_balance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReceiptMinted {
+    pub vesting: Pubkey,
+    pub receipt_mint: Pubkey,
+    /// Initial holder (the beneficiary)
+    pub holder: Pubkey,
+}
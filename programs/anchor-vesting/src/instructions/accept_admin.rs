@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VestingError;
+use crate::events::AdminTransferred;
+use crate::state::{Config, VestingAccount, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = vesting_account.pending_admin != Pubkey::default()
+            && vesting_account.pending_admin == new_admin.key() @ VestingError::NotPendingAdmin,
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(0) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Second step of an admin transfer: the pending admin takes over. The PDA
+/// keeps signing with `seed_admin`, so existing vaults are unaffected.
+pub fn accept_admin_handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let vesting = &mut ctx.accounts.vesting_account;
+    let previous_admin = vesting.admin;
+    vesting.admin = vesting.pending_admin;
+    vesting.pending_admin = Pubkey::default();
+
+    emit!(AdminTransferred {
+        vesting: vesting.key(),
+        previous_admin,
+        new_admin: vesting.admin,
+    });
+
+    Ok(())
+}
@@ -5,19 +5,28 @@ use anchor_spl::{
 };
 
 use crate::errors::VestingError;
-use crate::state::VestingAccount;
+use crate::events::{BeneficiaryTransferred, TokensClaimed};
+use crate::state::{Config, VestingAccount, VestingStatus, CONFIG_SEED, PAUSE_CLAIM};
 
 #[derive(Accounts)]
 pub struct Claim<'info> {
+    /// The beneficiary or its registered claim delegate, or the receipt holder
     #[account(mut)]
-    pub beneficiary: Signer<'info>,
+    pub claimer: Signer<'info>,
+
+    /// CHECK: validated against vesting_account.beneficiary, or against the
+    /// receipt holder in the handler when the vesting has a receipt
+    pub beneficiary: UncheckedAccount<'info>,
 
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
-        has_one = beneficiary @ VestingError::UnauthorizedBeneficiary,
         has_one = mint @ VestingError::MintMismatch,
+        constraint = vesting_account.has_receipt()
+            || vesting_account.beneficiary == beneficiary.key() @ VestingError::UnauthorizedBeneficiary,
+        constraint = vesting_account.has_receipt()
+            || vesting_account.is_claimer(&claimer.key()) @ VestingError::UnauthorizedBeneficiary,
     )]
     pub vesting_account: Account<'info, VestingAccount>,
 
@@ -31,7 +40,7 @@ pub struct Claim<'info> {
 
     #[account(
         init_if_needed,
-        payer = beneficiary,
+        payer = claimer,
         associated_token::mint = mint,
         associated_token::authority = beneficiary,
         associated_token::token_program = token_program,
@@ -41,69 +50,140 @@ pub struct Claim<'info> {
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(PAUSE_CLAIM) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The claimer's receipt token account; required when the vesting has a receipt
+    pub receipt_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
-pub fn claim_handler(ctx: Context<Claim>) -> Result<()> {
-    let vesting = &ctx.accounts.vesting_account;
+/// Claim `amount` tokens, or everything claimable when `amount` is `None`
+pub fn claim_handler(ctx: Context<Claim>, amount: Option<u64>) -> Result<()> {
+    if ctx.accounts.vesting_account.has_receipt() {
+        sync_receipt_holder(
+            &mut ctx.accounts.vesting_account,
+            &ctx.accounts.claimer,
+            &ctx.accounts.beneficiary,
+            ctx.accounts.receipt_token_account.as_ref(),
+        )?;
+    }
+
+    release_tokens(
+        &mut ctx.accounts.vesting_account,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.beneficiary_token_account,
+        &ctx.accounts.token_program,
+        amount,
+    )
+}
+
+/// The holder of the receipt NFT is the beneficiary: require the claimer to hold
+/// it and claim to itself, and record it as beneficiary if the NFT has moved.
+fn sync_receipt_holder(
+    vesting: &mut Account<VestingAccount>,
+    claimer: &Signer,
+    beneficiary: &UncheckedAccount,
+    receipt_token_account: Option<&InterfaceAccount<TokenAccount>>,
+) -> Result<()> {
+    require_keys_eq!(
+        beneficiary.key(),
+        claimer.key(),
+        VestingError::UnauthorizedBeneficiary
+    );
+    let receipt = receipt_token_account.ok_or(VestingError::InvalidReceipt)?;
+    require!(
+        receipt.mint == vesting.receipt_mint
+            && receipt.owner == claimer.key()
+            && receipt.amount == 1,
+        VestingError::InvalidReceipt
+    );
+
+    if vesting.beneficiary != claimer.key() {
+        let previous_beneficiary = vesting.beneficiary;
+        vesting.beneficiary = claimer.key();
+        vesting.claim_delegate = Pubkey::default();
+
+        emit!(BeneficiaryTransferred {
+            vesting: vesting.key(),
+            previous_beneficiary,
+            new_beneficiary: vesting.beneficiary,
+            admin_cosigned: false,
+        });
+    }
+
+    Ok(())
+}
 
-    // Ensure vault is funded
-    require!(ctx.accounts.vault.amount > 0, VestingError::NotFunded);
+/// Transfer `amount` (or everything claimable) from the vault to `destination`
+/// and record it as released. Callers validate who may trigger the release.
+pub(crate) fn release_tokens<'info>(
+    vesting: &mut Account<'info, VestingAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: Option<u64>,
+) -> Result<()> {
+    // Ensure vesting is funded and still active
+    require!(vesting.status != VestingStatus::Created, VestingError::NotFunded);
+    require!(!vesting.is_revoked(), VestingError::AlreadyRevoked);
 
     // Get current time from Solana Clock
     let now = Clock::get()?.unix_timestamp;
 
     // Calculate claimable amount
     let claimable = vesting.claimable(now)?;
-    require!(claimable > 0, VestingError::NothingToClaim);
-
-    // Build PDA signer seeds
-    let beneficiary_key = vesting.beneficiary;
-    let mint_key = vesting.mint;
-    let seed_bytes = vesting.seed.to_le_bytes();
-    let bump_bytes = [vesting.bump];
-
-    let signer_seeds: &[&[u8]] = &[
-        b"vesting",
-        beneficiary_key.as_ref(),
-        mint_key.as_ref(),
-        &seed_bytes,
-        &bump_bytes,
-    ];
-    let signer = &[signer_seeds];
-
-    // Transfer claimable tokens from vault to beneficiary
-    let transfer_accounts = TransferChecked {
-        from: ctx.accounts.vault.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.beneficiary_token_account.to_account_info(),
-        authority: ctx.accounts.vesting_account.to_account_info(),
-    };
-
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_accounts,
-        signer,
-    );
-
-    token_interface::transfer_checked(
-        cpi_ctx,
-        claimable,
-        ctx.accounts.mint.decimals,
-    )?;
+    let amount = amount.unwrap_or(claimable);
+    require!(amount <= claimable, VestingError::ClaimAmountExceedsClaimable);
+    require!(amount > 0, VestingError::NothingToClaim);
+
+    // Transfer the claimed tokens from vault (PDA-signed)
+    vesting.with_signer_seeds(|signer| {
+        let transfer_accounts = TransferChecked {
+            from: vault.to_account_info(),
+            mint: mint.to_account_info(),
+            to: destination.to_account_info(),
+            authority: vesting.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            transfer_accounts,
+            signer,
+        );
+
+        token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)
+    })?;
 
     // Update released_amount (monotonically increasing)
-    let vesting = &mut ctx.accounts.vesting_account;
     vesting.released_amount = vesting
         .released_amount
-        .checked_add(claimable)
+        .checked_add(amount)
         .ok_or(VestingError::Overflow)?;
-
-    msg!(
-        "Claimed {} tokens. Total released: {}/{}",
-        claimable,
-        vesting.released_amount,
-        vesting.total_amount,
-    );
+    if vesting.released_amount == vesting.total_amount {
+        vesting.status = VestingStatus::Completed;
+    }
+
+    emit!(TokensClaimed {
+        vesting: vesting.key(),
+        beneficiary: vesting.beneficiary,
+        mint: vesting.mint,
+        seed: vesting.seed,
+        amount,
+        released_amount: vesting.released_amount,
+        total_amount: vesting.total_amount,
+        vault_balance: vault
+            .amount
+            .checked_sub(amount)
+            .ok_or(VestingError::Overflow)?,
+        timestamp: now,
+    });
 
     Ok(())
 }
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::errors::VestingError;
+use crate::events::MerkleTokensClaimed;
+use crate::instructions::create_vesting::validate_schedule;
+use crate::state::{
+    verify_merkle_proof, ClaimStatus, Config, MerkleLeaf, MerkleVesting, CLAIM_STATUS_SEED,
+    CONFIG_SEED, MERKLE_VESTING_SEED, PAUSE_CLAIM,
+};
+
+#[derive(Accounts)]
+#[instruction(leaf: MerkleLeaf)]
+pub struct ClaimMerkle<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = mint @ VestingError::MintMismatch,
+    )]
+    pub merkle_vesting: Account<'info, MerkleVesting>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_vesting,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        space = 8 + ClaimStatus::INIT_SPACE,
+        seeds = [
+            CLAIM_STATUS_SEED,
+            merkle_vesting.key().as_ref(),
+            beneficiary.key().as_ref(),
+            leaf.hash()?.as_ref(),
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(PAUSE_CLAIM) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Claim from a merkle distributor. The first claim proves `leaf` against the
+/// root and records its hash; later claims only re-send the same leaf. The
+/// claim status is keyed by the leaf hash, so a recipient listed in several
+/// leaves claims each of them independently.
+pub fn claim_merkle_handler(
+    ctx: Context<ClaimMerkle>,
+    leaf: MerkleLeaf,
+    proof: Vec<[u8; 32]>,
+    amount: Option<u64>,
+) -> Result<()> {
+    require_keys_eq!(
+        leaf.beneficiary,
+        ctx.accounts.beneficiary.key(),
+        VestingError::UnauthorizedBeneficiary
+    );
+
+    let leaf_hash = leaf.hash()?;
+    let claim_status = &mut ctx.accounts.claim_status;
+    if claim_status.leaf_hash == [0; 32] {
+        require!(
+            verify_merkle_proof(&proof, &ctx.accounts.merkle_vesting.merkle_root, leaf_hash),
+            VestingError::InvalidMerkleProof
+        );
+        claim_status.leaf_hash = leaf_hash;
+        claim_status.bump = ctx.bumps.claim_status;
+    }
+
+    validate_schedule(
+        leaf.total_amount,
+        leaf.start_time,
+        leaf.cliff_time,
+        leaf.end_time,
+        leaf.schedule_kind,
+        leaf.period_seconds,
+        &leaf.schedule_points,
+        leaf.cliff_amount,
+        leaf.accrue_from_cliff,
+    )?;
+
+    // Same schedule math as a standalone vesting
+    let now = Clock::get()?.unix_timestamp;
+    let claimable = leaf.to_vesting(claim_status.released_amount).claimable(now)?;
+    let amount = amount.unwrap_or(claimable);
+    require!(amount <= claimable, VestingError::ClaimAmountExceedsClaimable);
+    require!(amount > 0, VestingError::NothingToClaim);
+
+    // Transfer the claimed tokens from vault (PDA-signed)
+    let merkle_vesting = &ctx.accounts.merkle_vesting;
+    let seed_bytes = merkle_vesting.seed.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        MERKLE_VESTING_SEED,
+        merkle_vesting.admin.as_ref(),
+        merkle_vesting.mint.as_ref(),
+        &seed_bytes,
+        &[merkle_vesting.bump],
+    ]];
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.vault.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.beneficiary_token_account.to_account_info(),
+        authority: merkle_vesting.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_accounts,
+        signer_seeds,
+    );
+    token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    claim_status.released_amount = claim_status
+        .released_amount
+        .checked_add(amount)
+        .ok_or(VestingError::Overflow)?;
+    let merkle_vesting = &mut ctx.accounts.merkle_vesting;
+    merkle_vesting.released_amount = merkle_vesting
+        .released_amount
+        .checked_add(amount)
+        .ok_or(VestingError::Overflow)?;
+
+    emit!(MerkleTokensClaimed {
+        merkle_vesting: merkle_vesting.key(),
+        beneficiary: leaf.beneficiary,
+        mint: merkle_vesting.mint,
+        amount,
+        released_amount: claim_status.released_amount,
+        total_amount: leaf.total_amount,
+        vault_balance: ctx
+            .accounts
+            .vault
+            .amount
+            .checked_sub(amount)
+            .ok_or(VestingError::Overflow)?,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::errors::VestingError;
+use crate::instructions::claim::release_tokens;
+use crate::state::{Config, VestingAccount, CONFIG_SEED, PAUSE_CLAIM};
+
+#[derive(Accounts)]
+pub struct ClaimTo<'info> {
+    pub beneficiary: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = beneficiary @ VestingError::UnauthorizedBeneficiary,
+        constraint = !vesting_account.has_receipt() @ VestingError::NotAllowedWithReceipt,
+        has_one = mint @ VestingError::MintMismatch,
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vesting_account,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Any token account for the vesting mint (multisig vault, exchange deposit, ...)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(PAUSE_CLAIM) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Same as `claim`, but sends the tokens to a beneficiary-chosen token account
+pub fn claim_to_handler(ctx: Context<ClaimTo>, amount: Option<u64>) -> Result<()> {
+    release_tokens(
+        &mut ctx.accounts.vesting_account,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.destination,
+        &ctx.accounts.token_program,
+        amount,
+    )
+}
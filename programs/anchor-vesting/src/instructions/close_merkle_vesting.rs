@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::errors::VestingError;
+use crate::events::MerkleVestingClosed;
+use crate::state::{
+    ClaimStatus, Config, MerkleVesting, CLAIM_STATUS_SEED, CONFIG_SEED, MERKLE_VESTING_SEED,
+};
+
+#[derive(Accounts)]
+pub struct CloseMerkleVesting<'info> {
+    /// Receives the unclaimed tokens and the rent of the distributor and its vault
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        close = admin,
+        has_one = admin @ VestingError::UnauthorizedAdmin,
+        has_one = mint @ VestingError::MintMismatch,
+    )]
+    pub merkle_vesting: Account<'info, MerkleVesting>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_vesting,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = admin,
+        associated_token::token_program = token_program,
+    )]
+    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(0) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Close a settled merkle distributor: once every token is claimed, or from
+/// its clawback time, the vault balance and all rent go back to the admin.
+/// Unclaimed leaves can no longer be claimed afterwards.
+pub fn close_merkle_vesting_handler(ctx: Context<CloseMerkleVesting>) -> Result<()> {
+    let merkle_vesting = &ctx.accounts.merkle_vesting;
+    let now = Clock::get()?.unix_timestamp;
+    require!(merkle_vesting.is_settled(now), VestingError::VestingNotComplete);
+
+    let seed_bytes = merkle_vesting.seed.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        MERKLE_VESTING_SEED,
+        merkle_vesting.admin.as_ref(),
+        merkle_vesting.mint.as_ref(),
+        &seed_bytes,
+        &[merkle_vesting.bump],
+    ]];
+
+    // Unclaimed allocations plus anything sent straight to the vault
+    let reclaimed_amount = ctx.accounts.vault.amount;
+    if reclaimed_amount > 0 {
+        let transfer_accounts = TransferChecked {
+            from: ctx.accounts.vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.admin_token_account.to_account_info(),
+            authority: merkle_vesting.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, reclaimed_amount, ctx.accounts.mint.decimals)?;
+    }
+
+    // Close the vault, returning its rent to the admin
+    let close_accounts = CloseAccount {
+        account: ctx.accounts.vault.to_account_info(),
+        destination: ctx.accounts.admin.to_account_info(),
+        authority: merkle_vesting.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        close_accounts,
+        signer_seeds,
+    );
+    token_interface::close_account(cpi_ctx)?;
+
+    // The distributor itself is closed to the admin by the `close` constraint
+    emit!(MerkleVestingClosed {
+        merkle_vesting: merkle_vesting.key(),
+        admin: merkle_vesting.admin,
+        mint: merkle_vesting.mint,
+        seed: merkle_vesting.seed,
+        reclaimed_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseClaimStatus<'info> {
+    /// Paid the claim status rent on the first claim and gets it back
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// CHECK: only its address is used; it must already be closed, otherwise a
+    /// fresh claim status could replay the leaf's claims
+    #[account(constraint = merkle_vesting.data_is_empty() @ VestingError::InvalidStatus)]
+    pub merkle_vesting: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [
+            CLAIM_STATUS_SEED,
+            merkle_vesting.key().as_ref(),
+            beneficiary.key().as_ref(),
+            claim_status.leaf_hash.as_ref(),
+        ],
+        bump = claim_status.bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+}
+
+/// Return a claim status's rent to its beneficiary once the distributor is closed
+pub fn close_claim_status_handler(_ctx: Context<CloseClaimStatus>) -> Result<()> {
+    Ok(())
+}
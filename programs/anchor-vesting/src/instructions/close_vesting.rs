@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::{self, Burn, Token2022},
+    token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::errors::VestingError;
+use crate::events::VestingClosed;
+use crate::state::{Config, VestingAccount, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct CloseVesting<'info> {
+    /// The current admin; receives the rent of the vesting account and the vault.
+    /// After `accept_admin` that is the new admin, not whoever paid it at creation.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        close = admin,
+        has_one = admin @ VestingError::UnauthorizedAdmin,
+        has_one = mint @ VestingError::MintMismatch,
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vesting_account,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Receives any surplus tokens sent straight to the vault
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = admin,
+        associated_token::token_program = token_program,
+    )]
+    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(0) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The receipt NFT mint; required when the vesting has a receipt
+    #[account(mut)]
+    pub receipt_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Whichever token account currently holds the receipt NFT
+    #[account(mut)]
+    pub receipt_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+}
+
+pub fn close_vesting_handler(ctx: Context<CloseVesting>) -> Result<()> {
+    let vesting = &ctx.accounts.vesting_account;
+
+    // Only fully released (or revoked) vestings can be closed
+    require!(vesting.is_complete(), VestingError::VestingNotComplete);
+
+    vesting.with_signer_seeds(|signer| {
+        // Nothing is owed to the beneficiary any more, so whatever is left in the
+        // vault is surplus (tokens sent straight to it) and goes back to the admin
+        let surplus = ctx.accounts.vault.amount;
+        if surplus > 0 {
+            let transfer_accounts = TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.admin_token_account.to_account_info(),
+                authority: ctx.accounts.vesting_account.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_accounts,
+                signer,
+            );
+
+            token_interface::transfer_checked(cpi_ctx, surplus, ctx.accounts.mint.decimals)?;
+        }
+
+        // Close the vault, returning its rent to the admin
+        let close_accounts = CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.admin.to_account_info(),
+            authority: ctx.accounts.vesting_account.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            close_accounts,
+            signer,
+        );
+
+        token_interface::close_account(cpi_ctx)?;
+
+        // Burn the receipt as its permanent delegate, wherever it is held, then
+        // close its mint and return the rent to the admin
+        if vesting.has_receipt() {
+            let (Some(receipt_mint), Some(receipt_token_account), Some(token_2022_program)) = (
+                &ctx.accounts.receipt_mint,
+                &ctx.accounts.receipt_token_account,
+                &ctx.accounts.token_2022_program,
+            ) else {
+                return err!(VestingError::InvalidReceipt);
+            };
+            require!(
+                receipt_mint.key() == vesting.receipt_mint
+                    && receipt_token_account.mint == vesting.receipt_mint
+                    && receipt_token_account.amount == 1,
+                VestingError::InvalidReceipt
+            );
+
+            let burn_accounts = Burn {
+                mint: receipt_mint.to_account_info(),
+                from: receipt_token_account.to_account_info(),
+                authority: ctx.accounts.vesting_account.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(
+                token_2022_program.to_account_info(),
+                burn_accounts,
+                signer,
+            );
+
+            token_2022::burn(cpi_ctx, 1)?;
+
+            // Supply is back to zero, so the mint can be closed as well
+            let close_accounts = token_2022::CloseAccount {
+                account: receipt_mint.to_account_info(),
+                destination: ctx.accounts.admin.to_account_info(),
+                authority: ctx.accounts.vesting_account.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(
+                token_2022_program.to_account_info(),
+                close_accounts,
+                signer,
+            );
+
+            token_2022::close_account(cpi_ctx)?;
+        }
+
+        Ok(())
+    })?;
+
+    // The vesting account itself is closed to the admin by the `close` constraint
+    emit!(VestingClosed {
+        vesting: vesting.key(),
+        admin: vesting.admin,
+        mint: vesting.mint,
+        seed: vesting.seed,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::errors::VestingError;
+use crate::instructions::claim::release_tokens;
+use crate::state::{Config, VestingAccount, CONFIG_SEED, PAUSE_CLAIM};
+
+#[derive(Accounts)]
+pub struct CrankClaim<'info> {
+    /// Any keeper; pays fees and, if needed, the beneficiary ATA rent
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// CHECK: validated against vesting_account.beneficiary via has_one
+    pub beneficiary: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = beneficiary @ VestingError::UnauthorizedBeneficiary,
+        constraint = !vesting_account.has_receipt() @ VestingError::NotAllowedWithReceipt,
+        has_one = mint @ VestingError::MintMismatch,
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vesting_account,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(PAUSE_CLAIM) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Push everything claimable to the beneficiary's canonical ATA. Callable by
+/// anyone on vestings created with `allow_permissionless_claim`.
+pub fn crank_claim_handler(ctx: Context<CrankClaim>) -> Result<()> {
+    require!(
+        ctx.accounts.vesting_account.allow_permissionless_claim,
+        VestingError::PermissionlessClaimDisabled
+    );
+
+    release_tokens(
+        &mut ctx.accounts.vesting_account,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.beneficiary_token_account,
+        &ctx.accounts.token_program,
+        None,
+    )
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::instructions::{
+    create_vesting::{collect_creation_fee, initialize_vesting, CreateVestingParams},
+    deposit::fund_vesting,
+};
+use crate::errors::VestingError;
+use crate::state::{Config, VestingAccount, CONFIG_SEED, PAUSE_CREATE, PAUSE_DEPOSIT};
+
+#[derive(Accounts)]
+#[instruction(params: CreateVestingParams)]
+pub struct CreateAndFundVesting<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: beneficiary does not need to sign
+    pub beneficiary: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + VestingAccount::INIT_SPACE,
+        seeds = [
+            b"vesting",
+            admin.key().as_ref(),
+            beneficiary.key().as_ref(),
+            mint.key().as_ref(),
+            &params.seed.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    /// `init_if_needed`: anyone can create the ATA first, which must not block the grant
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = vesting_account,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = admin,
+        associated_token::token_program = token_program,
+    )]
+    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(PAUSE_CREATE | PAUSE_DEPOSIT) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: SOL fee recipient; only required when a creation fee is charged
+    #[account(mut, address = config.treasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    /// Deposit fee recipient; only required when a deposit fee is charged
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// `create_vesting` + `deposit` in one instruction, so the vesting is never
+/// observable in the unfunded `Created` state
+pub fn create_and_fund_vesting_handler(
+    ctx: Context<CreateAndFundVesting>,
+    params: CreateVestingParams,
+) -> Result<()> {
+    let creation_fee_lamports = collect_creation_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.admin,
+        ctx.accounts.treasury.as_ref(),
+        &ctx.accounts.system_program,
+    )?;
+
+    initialize_vesting(
+        &mut ctx.accounts.vesting_account,
+        ctx.accounts.admin.key(),
+        ctx.accounts.beneficiary.key(),
+        ctx.accounts.mint.key(),
+        ctx.bumps.vesting_account,
+        params,
+        creation_fee_lamports,
+    )?;
+
+    fund_vesting(
+        &mut ctx.accounts.vesting_account,
+        &ctx.accounts.admin,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.admin_token_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.config,
+        ctx.accounts.treasury_token_account.as_ref(),
+    )
+}
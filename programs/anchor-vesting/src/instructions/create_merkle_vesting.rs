@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::errors::VestingError;
+use crate::events::MerkleVestingCreated;
+use crate::instructions::create_vesting::collect_creation_fee;
+use crate::state::{
+    Config, MerkleVesting, CONFIG_SEED, MERKLE_VESTING_SEED, PAUSE_CREATE, PAUSE_DEPOSIT,
+};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CreateMerkleVesting<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + MerkleVesting::INIT_SPACE,
+        seeds = [
+            MERKLE_VESTING_SEED,
+            admin.key().as_ref(),
+            mint.key().as_ref(),
+            &seed.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub merkle_vesting: Account<'info, MerkleVesting>,
+
+    /// `init_if_needed`: anyone can create the ATA first, which must not block the grant
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_vesting,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = admin,
+        associated_token::token_program = token_program,
+    )]
+    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(PAUSE_CREATE | PAUSE_DEPOSIT) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: SOL fee recipient; only required when a creation fee is charged
+    #[account(mut, address = config.treasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    /// Deposit fee recipient; only required when a deposit fee is charged
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// Create a merkle distributor and fund its shared vault with the sum of all
+/// leaves. Recipients prove their leaf in `claim_merkle`; from `clawback_time`
+/// (0 = never) the admin may reclaim whatever is left with `close_merkle_vesting`.
+pub fn create_merkle_vesting_handler(
+    ctx: Context<CreateMerkleVesting>,
+    seed: u64,
+    merkle_root: [u8; 32],
+    total_amount: u64,
+    clawback_time: i64,
+) -> Result<()> {
+    require!(total_amount > 0, VestingError::InvalidAmount);
+    require!(
+        clawback_time == 0 || clawback_time > Clock::get()?.unix_timestamp,
+        VestingError::InvalidTimeRange
+    );
+
+    let creation_fee_lamports = collect_creation_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.admin,
+        ctx.accounts.treasury.as_ref(),
+        &ctx.accounts.system_program,
+    )?;
+
+    let merkle_vesting = &mut ctx.accounts.merkle_vesting;
+    merkle_vesting.admin = ctx.accounts.admin.key();
+    merkle_vesting.mint = ctx.accounts.mint.key();
+    merkle_vesting.merkle_root = merkle_root;
+    merkle_vesting.seed = seed;
+    merkle_vesting.bump = ctx.bumps.merkle_vesting;
+    merkle_vesting.total_amount = total_amount;
+    merkle_vesting.released_amount = 0;
+    merkle_vesting.clawback_time = clawback_time;
+
+    // Transfer total_amount from admin to vault
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.admin_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        authority: ctx.accounts.admin.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_accounts);
+    token_interface::transfer_checked(cpi_ctx, total_amount, ctx.accounts.mint.decimals)?;
+
+    // Transfer the protocol fee from admin to treasury
+    let fee_amount = ctx
+        .accounts
+        .config
+        .deposit_fee(&ctx.accounts.admin.key(), total_amount)?;
+    if fee_amount > 0 {
+        let treasury_token_account = ctx
+            .accounts
+            .treasury_token_account
+            .as_ref()
+            .ok_or(VestingError::MissingTreasuryAccount)?;
+        let fee_accounts = TransferChecked {
+            from: ctx.accounts.admin_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: treasury_token_account.to_account_info(),
+            authority: ctx.accounts.admin.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), fee_accounts);
+        token_interface::transfer_checked(cpi_ctx, fee_amount, ctx.accounts.mint.decimals)?;
+    }
+
+    emit!(MerkleVestingCreated {
+        merkle_vesting: ctx.accounts.merkle_vesting.key(),
+        admin: ctx.accounts.admin.key(),
+        mint: ctx.accounts.mint.key(),
+        seed,
+        merkle_root,
+        total_amount,
+        creation_fee_lamports,
+        fee_amount,
+        clawback_time,
+    });
+
+    Ok(())
+}
@@ -1,14 +1,44 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
 use anchor_spl::{
     associated_token::AssociatedToken,
+    token_2022::{self, spl_token_2022::instruction::AuthorityType, MintTo, SetAuthority, Token2022},
+    token_2022_extensions::{
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::TokenMetadata, token_metadata_initialize,
+        TokenMetadataInitialize,
+    },
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::errors::VestingError;
-use crate::state::VestingAccount;
+use crate::events::{ReceiptMinted, VestingCreated};
+use crate::state::{
+    Config, ScheduleKind, SchedulePoint, VestingAccount, VestingStatus, CONFIG_SEED,
+    MAX_SCHEDULE_POINTS, PAUSE_CREATE, VESTING_LAYOUT_VERSION,
+};
+
+/// Parameters of a single vesting, shared by `create_vesting`,
+/// `create_and_fund_vesting` and each `create_vesting_batch` entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateVestingParams {
+    pub seed: u64,
+    pub total_amount: u64,
+    pub start_time: i64,
+    pub cliff_time: i64,
+    pub end_time: i64,
+    pub revocable: bool,
+    pub schedule_kind: ScheduleKind,
+    pub period_seconds: u64,
+    pub schedule_points: Vec<SchedulePoint>,
+    pub cliff_amount: u64,
+    pub accrue_from_cliff: bool,
+    pub allow_permissionless_claim: bool,
+    pub transferable: bool,
+}
 
 #[derive(Accounts)]
-#[instruction(seed: u64)]
+#[instruction(params: CreateVestingParams)]
 pub struct CreateVesting<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -24,16 +54,18 @@ pub struct CreateVesting<'info> {
         space = 8 + VestingAccount::INIT_SPACE,
         seeds = [
             b"vesting",
+            admin.key().as_ref(),
             beneficiary.key().as_ref(),
             mint.key().as_ref(),
-            &seed.to_le_bytes(),
+            &params.seed.to_le_bytes(),
         ],
         bump,
     )]
     pub vesting_account: Account<'info, VestingAccount>,
 
+    /// `init_if_needed`: anyone can create the ATA first, which must not block the grant
     #[account(
-        init,
+        init_if_needed,
         payer = admin,
         associated_token::mint = mint,
         associated_token::authority = vesting_account,
@@ -44,15 +76,298 @@ pub struct CreateVesting<'info> {
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(PAUSE_CREATE) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: SOL fee recipient; only required when a creation fee is charged
+    #[account(mut, address = config.treasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    /// Pass to represent the position as a 1-of-1 Token-2022 NFT receipt
+    #[account(
+        init,
+        payer = admin,
+        mint::decimals = 0,
+        mint::authority = vesting_account,
+        mint::token_program = token_2022_program,
+        extensions::metadata_pointer::authority = vesting_account,
+        extensions::metadata_pointer::metadata_address = receipt_mint,
+        extensions::permanent_delegate::delegate = vesting_account,
+        extensions::close_authority::authority = vesting_account,
+    )]
+    pub receipt_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// The beneficiary's receipt ATA, receiving the NFT
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_2022_program,
+    )]
+    pub receipt_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_2022_program: Option<Program<'info, Token2022>>,
 }
 
-pub fn create_vesting_handler(
-    ctx: Context<CreateVesting>,
-    seed: u64,
+pub fn create_vesting_handler(ctx: Context<CreateVesting>, params: CreateVestingParams) -> Result<()> {
+    let creation_fee_lamports = collect_creation_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.admin,
+        ctx.accounts.treasury.as_ref(),
+        &ctx.accounts.system_program,
+    )?;
+
+    initialize_vesting(
+        &mut ctx.accounts.vesting_account,
+        ctx.accounts.admin.key(),
+        ctx.accounts.beneficiary.key(),
+        ctx.accounts.mint.key(),
+        ctx.bumps.vesting_account,
+        params,
+        creation_fee_lamports,
+    )?;
+
+    if let Some(receipt_mint) = &ctx.accounts.receipt_mint {
+        let (Some(receipt_token_account), Some(token_2022_program)) = (
+            &ctx.accounts.receipt_token_account,
+            &ctx.accounts.token_2022_program,
+        ) else {
+            return err!(VestingError::InvalidReceipt);
+        };
+        mint_receipt(
+            &mut ctx.accounts.vesting_account,
+            &ctx.accounts.admin,
+            receipt_mint,
+            receipt_token_account,
+            token_2022_program,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Receipt metadata; the mint address already identifies the position
+const RECEIPT_NAME: &str = "Vesting Receipt";
+const RECEIPT_SYMBOL: &str = "VEST";
+
+/// Mint the vesting's 1-of-1 NFT receipt to the beneficiary and fix its supply.
+/// The vesting PDA stays permanent delegate and close authority so `close_vesting`
+/// can burn it and reclaim the mint's rent.
+fn mint_receipt<'info>(
+    vesting: &mut Account<'info, VestingAccount>,
+    admin: &Signer<'info>,
+    receipt_mint: &InterfaceAccount<'info, Mint>,
+    receipt_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_2022_program: &Program<'info, Token2022>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    // A revocable grant would pay vested tokens to a stale beneficiary on revoke
+    require!(!vesting.revocable, VestingError::NotAllowedWithReceipt);
+
+    // Top up the mint's rent for the metadata it grows by
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(vesting.key()))?,
+        mint: receipt_mint.key(),
+        name: RECEIPT_NAME.to_string(),
+        symbol: RECEIPT_SYMBOL.to_string(),
+        uri: String::new(),
+        additional_metadata: vec![],
+    };
+    let mint_info = receipt_mint.to_account_info();
+    let size = mint_info.data_len() + metadata.tlv_size_of()?;
+    let shortfall = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(mint_info.lamports());
+    if shortfall > 0 {
+        let transfer_accounts = Transfer {
+            from: admin.to_account_info(),
+            to: mint_info.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.to_account_info(), transfer_accounts);
+        system_program::transfer(cpi_ctx, shortfall)?;
+    }
+
+    vesting.with_signer_seeds(|signer| {
+        let token_program = token_2022_program.to_account_info();
+
+        let metadata_accounts = TokenMetadataInitialize {
+            program_id: token_program.clone(),
+            metadata: mint_info.clone(),
+            update_authority: vesting.to_account_info(),
+            mint_authority: vesting.to_account_info(),
+            mint: mint_info.clone(),
+        };
+        token_metadata_initialize(
+            CpiContext::new_with_signer(token_program.clone(), metadata_accounts, signer),
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+        )?;
+
+        let mint_accounts = MintTo {
+            mint: mint_info.clone(),
+            to: receipt_token_account.to_account_info(),
+            authority: vesting.to_account_info(),
+        };
+        token_2022::mint_to(
+            CpiContext::new_with_signer(token_program.clone(), mint_accounts, signer),
+            1,
+        )?;
+
+        // Supply is fixed at one
+        let authority_accounts = SetAuthority {
+            current_authority: vesting.to_account_info(),
+            account_or_mint: mint_info.clone(),
+        };
+        token_2022::set_authority(
+            CpiContext::new_with_signer(token_program, authority_accounts, signer),
+            AuthorityType::MintTokens,
+            None,
+        )
+    })?;
+
+    vesting.receipt_mint = receipt_mint.key();
+
+    emit!(ReceiptMinted {
+        vesting: vesting.key(),
+        receipt_mint: vesting.receipt_mint,
+        holder: vesting.beneficiary,
+    });
+
+    Ok(())
+}
+
+/// Charge the config's flat SOL creation fee to `admin` unless it is fee-exempt.
+/// Returns the lamports paid.
+pub(crate) fn collect_creation_fee<'info>(
+    config: &Account<'info, Config>,
+    admin: &Signer<'info>,
+    treasury: Option<&UncheckedAccount<'info>>,
+    system_program: &Program<'info, System>,
+) -> Result<u64> {
+    let fee = config.creation_fee(&admin.key());
+    if fee > 0 {
+        let treasury = treasury.ok_or(VestingError::MissingTreasuryAccount)?;
+        let transfer_accounts = Transfer {
+            from: admin.to_account_info(),
+            to: treasury.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.to_account_info(), transfer_accounts);
+        system_program::transfer(cpi_ctx, fee)?;
+    }
+    Ok(fee)
+}
+
+/// Validate the schedule and write the initial state of a freshly created vesting
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn initialize_vesting(
+    vesting: &mut Account<VestingAccount>,
+    admin: Pubkey,
+    beneficiary: Pubkey,
+    mint: Pubkey,
+    bump: u8,
+    params: CreateVestingParams,
+    creation_fee_lamports: u64,
+) -> Result<()> {
+    let CreateVestingParams {
+        seed,
+        total_amount,
+        start_time,
+        cliff_time,
+        end_time,
+        revocable,
+        schedule_kind,
+        period_seconds,
+        schedule_points,
+        cliff_amount,
+        accrue_from_cliff,
+        allow_permissionless_claim,
+        transferable,
+    } = params;
+
+    validate_schedule(
+        total_amount,
+        start_time,
+        cliff_time,
+        end_time,
+        schedule_kind,
+        period_seconds,
+        &schedule_points,
+        cliff_amount,
+        accrue_from_cliff,
+    )?;
+
+    vesting.admin = admin;
+    vesting.beneficiary = beneficiary;
+    vesting.mint = mint;
+    vesting.total_amount = total_amount;
+    vesting.released_amount = 0;
+    vesting.start_time = start_time;
+    vesting.cliff_time = cliff_time;
+    vesting.end_time = end_time;
+    vesting.seed = seed;
+    vesting.bump = bump;
+    vesting.revocable = revocable;
+    vesting.revoked_at = 0;
+    vesting.status = VestingStatus::Created;
+    vesting.legacy_seeds = false;
+    vesting.schedule_kind = schedule_kind;
+    vesting.period_seconds = period_seconds;
+    vesting.schedule_len = schedule_points.len() as u8;
+    vesting.schedule_points = [SchedulePoint::default(); MAX_SCHEDULE_POINTS];
+    vesting.schedule_points[..schedule_points.len()].copy_from_slice(&schedule_points);
+    vesting.cliff_amount = cliff_amount;
+    vesting.accrue_from_cliff = accrue_from_cliff;
+    vesting.allow_permissionless_claim = allow_permissionless_claim;
+    vesting.claim_delegate = Pubkey::default();
+    vesting.pending_admin = Pubkey::default();
+    vesting.seed_admin = admin;
+    vesting.transferable = transferable;
+    vesting.seed_beneficiary = beneficiary;
+    vesting.receipt_mint = Pubkey::default();
+    vesting.layout_version = VESTING_LAYOUT_VERSION;
+
+    emit!(VestingCreated {
+        vesting: vesting.key(),
+        admin: vesting.admin,
+        beneficiary: vesting.beneficiary,
+        mint: vesting.mint,
+        seed: vesting.seed,
+        total_amount: vesting.total_amount,
+        start_time: vesting.start_time,
+        cliff_time: vesting.cliff_time,
+        end_time: vesting.end_time,
+        revocable: vesting.revocable,
+        schedule_kind: vesting.schedule_kind,
+        period_seconds: vesting.period_seconds,
+        cliff_amount: vesting.cliff_amount,
+        accrue_from_cliff: vesting.accrue_from_cliff,
+        allow_permissionless_claim: vesting.allow_permissionless_claim,
+        transferable: vesting.transferable,
+        creation_fee_lamports,
+    });
+
+    Ok(())
+}
+
+/// Validate the vesting parameters shared by every way of creating a schedule
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn validate_schedule(
     total_amount: u64,
     start_time: i64,
     cliff_time: i64,
     end_time: i64,
+    schedule_kind: ScheduleKind,
+    period_seconds: u64,
+    schedule_points: &[SchedulePoint],
+    cliff_amount: u64,
+    accrue_from_cliff: bool,
 ) -> Result<()> {
     // Validate parameters
     require!(total_amount > 0, VestingError::InvalidAmount);
@@ -60,26 +375,50 @@ pub fn create_vesting_handler(
         start_time <= cliff_time && cliff_time <= end_time && start_time < end_time,
         VestingError::InvalidTimeRange
     );
+    require!(cliff_amount <= total_amount, VestingError::InvalidCliffAmount);
+    let accrual_start = if accrue_from_cliff { cliff_time } else { start_time };
+    match schedule_kind {
+        ScheduleKind::Linear => require!(period_seconds == 0, VestingError::InvalidPeriod),
+        ScheduleKind::Periodic => require!(
+            period_seconds > 0 && period_seconds <= (end_time - accrual_start) as u64,
+            VestingError::InvalidPeriod
+        ),
+        ScheduleKind::CustomLinear | ScheduleKind::CustomStep => {
+            // The table alone defines a custom schedule
+            require!(period_seconds == 0, VestingError::InvalidPeriod);
+            require!(cliff_amount == 0, VestingError::InvalidCliffAmount);
+            require!(!accrue_from_cliff, VestingError::InvalidSchedule);
+            validate_schedule_points(schedule_points, start_time, end_time, total_amount)?;
+        }
+    }
+    if matches!(schedule_kind, ScheduleKind::Linear | ScheduleKind::Periodic) {
+        require!(schedule_points.is_empty(), VestingError::InvalidSchedule);
+    }
+    Ok(())
+}
 
-    let vesting = &mut ctx.accounts.vesting_account;
-    vesting.admin = ctx.accounts.admin.key();
-    vesting.beneficiary = ctx.accounts.beneficiary.key();
-    vesting.mint = ctx.accounts.mint.key();
-    vesting.total_amount = total_amount;
-    vesting.released_amount = 0;
-    vesting.start_time = start_time;
-    vesting.cliff_time = cliff_time;
-    vesting.end_time = end_time;
-    vesting.seed = seed;
-    vesting.bump = ctx.bumps.vesting_account;
-
-    msg!(
-        "Vesting created: beneficiary={}, mint={}, amount={}, seed={}",
-        vesting.beneficiary,
-        vesting.mint,
-        vesting.total_amount,
-        vesting.seed,
+/// Custom tables must be non-decreasing in time and amount, start no earlier
+/// than start_time, and finish exactly at (end_time, total_amount)
+fn validate_schedule_points(
+    points: &[SchedulePoint],
+    start_time: i64,
+    end_time: i64,
+    total_amount: u64,
+) -> Result<()> {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return err!(VestingError::InvalidSchedule);
+    };
+    require!(points.len() <= MAX_SCHEDULE_POINTS, VestingError::InvalidSchedule);
+    require!(first.timestamp >= start_time, VestingError::InvalidSchedule);
+    require!(
+        points
+            .windows(2)
+            .all(|w| w[0].timestamp <= w[1].timestamp && w[0].amount <= w[1].amount),
+        VestingError::InvalidSchedule
+    );
+    require!(
+        last.timestamp == end_time && last.amount == total_amount,
+        VestingError::InvalidSchedule
     );
-
     Ok(())
 }
@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken, Create},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::errors::VestingError;
+use crate::instructions::{
+    create_vesting::{collect_creation_fee, initialize_vesting, CreateVestingParams},
+    deposit::fund_vesting,
+};
+use crate::state::{Config, VestingAccount, CONFIG_SEED, PAUSE_CREATE, PAUSE_DEPOSIT};
+
+/// One vesting of a `create_vesting_batch` call; same parameters as `create_vesting`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchVestingEntry {
+    pub beneficiary: Pubkey,
+    pub params: CreateVestingParams,
+}
+
+/// Remaining accounts: `[vesting_account(w), vault(w)]` per entry, in entry order
+#[derive(Accounts)]
+pub struct CreateVestingBatch<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = admin,
+        associated_token::token_program = token_program,
+    )]
+    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(PAUSE_CREATE | PAUSE_DEPOSIT) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: SOL fee recipient; only required when a creation fee is charged
+    #[account(mut, address = config.treasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    /// Deposit fee recipient; only required when a deposit fee is charged
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// `create_and_fund_vesting` for many beneficiaries of one mint in a single
+/// instruction. Each vesting PDA and its vault come from `remaining_accounts`.
+pub fn create_vesting_batch_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateVestingBatch<'info>>,
+    entries: Vec<BatchVestingEntry>,
+) -> Result<()> {
+    require!(
+        !entries.is_empty() && ctx.remaining_accounts.len() == entries.len() * 2,
+        VestingError::InvalidBatchAccounts
+    );
+
+    let admin_key = ctx.accounts.admin.key();
+    let mint_key = ctx.accounts.mint.key();
+    let space = 8 + VestingAccount::INIT_SPACE;
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+
+    for (entry, accounts) in entries.into_iter().zip(ctx.remaining_accounts.chunks_exact(2)) {
+        let (vesting_info, vault_info) = (&accounts[0], &accounts[1]);
+
+        let seed_bytes = entry.params.seed.to_le_bytes();
+        let (expected, bump) = Pubkey::find_program_address(
+            &[
+                b"vesting",
+                admin_key.as_ref(),
+                entry.beneficiary.as_ref(),
+                mint_key.as_ref(),
+                &seed_bytes,
+            ],
+            ctx.program_id,
+        );
+        require_keys_eq!(vesting_info.key(), expected, VestingError::InvalidBatchAccounts);
+
+        // Create the vesting PDA
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"vesting",
+            admin_key.as_ref(),
+            entry.beneficiary.as_ref(),
+            mint_key.as_ref(),
+            &seed_bytes,
+            &[bump],
+        ]];
+        create_pda_account(
+            &ctx.accounts.system_program,
+            &ctx.accounts.admin,
+            vesting_info,
+            rent_lamports,
+            space,
+            ctx.program_id,
+            signer_seeds,
+        )?;
+
+        // Create the vault if missing (`init_if_needed`); the ATA program rejects
+        // any other address, or an existing account with another owner or mint
+        associated_token::create_idempotent(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: ctx.accounts.admin.to_account_info(),
+                associated_token: vault_info.clone(),
+                authority: vesting_info.clone(),
+                mint: ctx.accounts.mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+
+        let creation_fee_lamports = collect_creation_fee(
+            &ctx.accounts.config,
+            &ctx.accounts.admin,
+            ctx.accounts.treasury.as_ref(),
+            &ctx.accounts.system_program,
+        )?;
+
+        // The new account is zeroed, so skip the discriminator check
+        let mut vesting = Account::<VestingAccount>::try_from_unchecked(vesting_info)?;
+        initialize_vesting(
+            &mut vesting,
+            admin_key,
+            entry.beneficiary,
+            mint_key,
+            bump,
+            entry.params,
+            creation_fee_lamports,
+        )?;
+
+        let vault = InterfaceAccount::<TokenAccount>::try_from(vault_info)?;
+        fund_vesting(
+            &mut vesting,
+            &ctx.accounts.admin,
+            &ctx.accounts.mint,
+            &vault,
+            &ctx.accounts.admin_token_account,
+            &ctx.accounts.token_program,
+            &ctx.accounts.config,
+            ctx.accounts.treasury_token_account.as_ref(),
+        )?;
+
+        // Persist the discriminator and state
+        vesting.exit(ctx.program_id)?;
+    }
+
+    Ok(())
+}
+
+/// `system_program::create_account`, or the transfer + allocate + assign that
+/// `init` falls back to when the address already holds lamports, since anyone
+/// can send lamports to a PDA to make `create_account` fail
+fn create_pda_account<'info>(
+    system_program: &Program<'info, System>,
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    rent_lamports: u64,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let current = account.lamports();
+    if current == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent_lamports,
+            space as u64,
+            owner,
+        );
+    }
+
+    if current < rent_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            rent_lamports - current,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        owner,
+    )
+}
@@ -5,7 +5,8 @@ use anchor_spl::{
 };
 
 use crate::errors::VestingError;
-use crate::state::VestingAccount;
+use crate::events::VestingFunded;
+use crate::state::{Config, VestingAccount, VestingStatus, CONFIG_SEED, PAUSE_DEPOSIT};
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
@@ -39,38 +40,99 @@ pub struct Deposit<'info> {
 
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(PAUSE_DEPOSIT) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+    /// Deposit fee recipient; only required when a deposit fee is charged
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
 pub fn deposit_handler(ctx: Context<Deposit>) -> Result<()> {
     let vesting = &ctx.accounts.vesting_account;
 
-    // Ensure vault is empty (not already funded)
-    require!(ctx.accounts.vault.amount == 0, VestingError::AlreadyFunded);
+    // A revoked vesting can no longer be funded
+    require!(!vesting.is_revoked(), VestingError::AlreadyRevoked);
+
+    // Ensure not already funded (tokens sent straight to the vault don't count)
+    require!(vesting.status == VestingStatus::Created, VestingError::AlreadyFunded);
 
+    fund_vesting(
+        &mut ctx.accounts.vesting_account,
+        &ctx.accounts.admin,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.admin_token_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.config,
+        ctx.accounts.treasury_token_account.as_ref(),
+    )
+}
+
+/// Transfer total_amount from the admin into the vault and mark the vesting funded.
+/// The protocol fee is paid on top, so the vault always holds exactly total_amount.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn fund_vesting<'info>(
+    vesting: &mut Account<'info, VestingAccount>,
+    admin: &Signer<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    admin_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    config: &Account<'info, Config>,
+    treasury_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+) -> Result<()> {
     // Transfer total_amount from admin to vault
     let transfer_accounts = TransferChecked {
-        from: ctx.accounts.admin_token_account.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.vault.to_account_info(),
-        authority: ctx.accounts.admin.to_account_info(),
+        from: admin_token_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: vault.to_account_info(),
+        authority: admin.to_account_info(),
     };
 
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_accounts,
-    );
-
-    token_interface::transfer_checked(
-        cpi_ctx,
-        vesting.total_amount,
-        ctx.accounts.mint.decimals,
-    )?;
-
-    msg!(
-        "Deposited {} tokens into vault for vesting {}",
-        vesting.total_amount,
-        ctx.accounts.vesting_account.key(),
-    );
+    let cpi_ctx = CpiContext::new(token_program.to_account_info(), transfer_accounts);
+
+    token_interface::transfer_checked(cpi_ctx, vesting.total_amount, mint.decimals)?;
+
+    // Transfer the protocol fee from admin to treasury
+    let fee_amount = config.deposit_fee(&admin.key(), vesting.total_amount)?;
+    if fee_amount > 0 {
+        let treasury_token_account =
+            treasury_token_account.ok_or(VestingError::MissingTreasuryAccount)?;
+        let fee_accounts = TransferChecked {
+            from: admin_token_account.to_account_info(),
+            mint: mint.to_account_info(),
+            to: treasury_token_account.to_account_info(),
+            authority: admin.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(token_program.to_account_info(), fee_accounts);
+        token_interface::transfer_checked(cpi_ctx, fee_amount, mint.decimals)?;
+    }
+
+    vesting.status = VestingStatus::Funded;
+
+    emit!(VestingFunded {
+        vesting: vesting.key(),
+        admin: vesting.admin,
+        mint: vesting.mint,
+        seed: vesting.seed,
+        amount: vesting.total_amount,
+        vault_balance: vault
+            .amount
+            .checked_add(vesting.total_amount)
+            .ok_or(VestingError::Overflow)?,
+        fee_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VestingError;
+use crate::events::ConfigUpdated;
+use crate::program::AnchorVesting;
+use crate::state::{Config, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [CONFIG_SEED],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, AnchorVesting>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ VestingError::UnauthorizedConfigAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the singleton config. Only the program upgrade authority may do this,
+/// so nobody can front-run deployment with their own pause switches or fees.
+pub fn initialize_config_handler(
+    ctx: Context<InitializeConfig>,
+    paused: bool,
+    pause_flags: u8,
+    treasury: Pubkey,
+    creation_fee_lamports: u64,
+    deposit_fee_bps: u16,
+    fee_exempt: Vec<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.paused = paused;
+    config.pause_flags = pause_flags;
+    config.bump = ctx.bumps.config;
+    config.set_fees(treasury, creation_fee_lamports, deposit_fee_bps, &fee_exempt)?;
+
+    emit!(ConfigUpdated {
+        authority: ctx.accounts.authority.key(),
+        paused,
+        pause_flags,
+        treasury,
+        creation_fee_lamports,
+        deposit_fee_bps,
+        fee_exempt_len: config.fee_exempt_len,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::errors::VestingError;
+use crate::state::{
+    Config, LegacyVestingAccount, ScheduleKind, SchedulePoint, VestingAccount, VestingStatus,
+    CONFIG_SEED, MAX_SCHEDULE_POINTS, VESTING_LAYOUT_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct MigrateVesting<'info> {
+    /// Anyone may migrate; the payer tops up rent for the larger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: pre-upgrade layout, validated by hand in the handler
+    #[account(mut, owner = crate::ID)]
+    pub vesting_account: UncheckedAccount<'info>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = vesting_account,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(0) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Upgrade a vesting created before admin-bound seeds to the current layout.
+/// The account keeps its address; `legacy_seeds` records how to sign for it.
+pub fn migrate_vesting_handler(ctx: Context<MigrateVesting>) -> Result<()> {
+    let account_info = ctx.accounts.vesting_account.to_account_info();
+
+    let legacy = {
+        let data = account_info.try_borrow_data()?;
+        require!(
+            data.len() == 8 + LegacyVestingAccount::SPACE
+                && &data[..8] == VestingAccount::DISCRIMINATOR,
+            VestingError::InvalidLegacyAccount
+        );
+        LegacyVestingAccount::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(legacy.mint, ctx.accounts.mint.key(), VestingError::MintMismatch);
+
+    // The address must be the legacy PDA, so the stored bump signs for it
+    let expected = Pubkey::create_program_address(
+        &[
+            b"vesting",
+            legacy.beneficiary.as_ref(),
+            legacy.mint.as_ref(),
+            &legacy.seed.to_le_bytes(),
+            &[legacy.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| error!(VestingError::InvalidLegacyAccount))?;
+    require_keys_eq!(expected, account_info.key(), VestingError::InvalidLegacyAccount);
+
+    // Legacy accounts tracked funding only through the vault balance; only a
+    // vault covering the whole outstanding amount counts as funded, so dust
+    // sent to an unfunded vault cannot block its deposit
+    let outstanding = legacy.total_amount - legacy.released_amount;
+    let status = if outstanding == 0 {
+        VestingStatus::Completed
+    } else if ctx.accounts.vault.amount >= outstanding {
+        VestingStatus::Funded
+    } else {
+        VestingStatus::Created
+    };
+
+    // Grow the account, topping up rent from the payer
+    let new_space = 8 + VestingAccount::INIT_SPACE;
+    let shortfall = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(account_info.lamports());
+    if shortfall > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: account_info.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, shortfall)?;
+    }
+    account_info.resize(new_space)?;
+
+    let migrated = VestingAccount {
+        admin: legacy.admin,
+        beneficiary: legacy.beneficiary,
+        mint: legacy.mint,
+        total_amount: legacy.total_amount,
+        released_amount: legacy.released_amount,
+        start_time: legacy.start_time,
+        cliff_time: legacy.cliff_time,
+        end_time: legacy.end_time,
+        seed: legacy.seed,
+        bump: legacy.bump,
+        revocable: false,
+        revoked_at: 0,
+        status,
+        legacy_seeds: true,
+        schedule_kind: ScheduleKind::Linear,
+        period_seconds: 0,
+        schedule_len: 0,
+        schedule_points: [SchedulePoint::default(); MAX_SCHEDULE_POINTS],
+        cliff_amount: 0,
+        accrue_from_cliff: false,
+        allow_permissionless_claim: false,
+        claim_delegate: Pubkey::default(),
+        pending_admin: Pubkey::default(),
+        seed_admin: legacy.admin,
+        transferable: false,
+        seed_beneficiary: legacy.beneficiary,
+        receipt_mint: Pubkey::default(),
+        layout_version: VESTING_LAYOUT_VERSION,
+    };
+    let mut data = account_info.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
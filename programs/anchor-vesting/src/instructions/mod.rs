@@ -1,7 +1,39 @@
 pub mod create_vesting;
 pub mod deposit;
 pub mod claim;
+pub mod claim_to;
+pub mod crank_claim;
+pub mod set_claim_delegate;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod transfer_beneficiary;
+pub mod initialize_config;
+pub mod update_config;
+pub mod revoke;
+pub mod close_vesting;
+pub mod migrate_vesting;
+pub mod create_and_fund_vesting;
+pub mod create_vesting_batch;
+pub mod create_merkle_vesting;
+pub mod claim_merkle;
+pub mod close_merkle_vesting;
 
 pub use create_vesting::*;
 pub use deposit::*;
 pub use claim::*;
+pub use claim_to::*;
+pub use crank_claim::*;
+pub use set_claim_delegate::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use transfer_beneficiary::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use revoke::*;
+pub use close_vesting::*;
+pub use migrate_vesting::*;
+pub use create_and_fund_vesting::*;
+pub use create_vesting_batch::*;
+pub use create_merkle_vesting::*;
+pub use claim_merkle::*;
+pub use close_merkle_vesting::*;
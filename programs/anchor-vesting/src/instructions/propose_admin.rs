@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VestingError;
+use crate::events::AdminProposed;
+use crate::state::{Config, VestingAccount, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ VestingError::UnauthorizedAdmin,
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(0) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// First step of an admin transfer: record `new_admin` as pending. The current
+/// admin keeps full authority until the proposal is accepted; proposing
+/// `Pubkey::default()` cancels an outstanding proposal.
+pub fn propose_admin_handler(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let vesting = &mut ctx.accounts.vesting_account;
+    vesting.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        vesting: vesting.key(),
+        admin: vesting.admin,
+        pending_admin: new_admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::errors::VestingError;
+use crate::events::VestingRevoked;
+use crate::state::{Config, VestingAccount, VestingStatus, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct Revoke<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: validated against vesting_account.beneficiary via has_one
+    pub beneficiary: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = admin @ VestingError::UnauthorizedAdmin,
+        has_one = beneficiary @ VestingError::UnauthorizedBeneficiary,
+        has_one = mint @ VestingError::MintMismatch,
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vesting_account,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = admin,
+        associated_token::token_program = token_program,
+    )]
+    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(0) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn revoke_handler(ctx: Context<Revoke>) -> Result<()> {
+    let vesting = &ctx.accounts.vesting_account;
+
+    require!(vesting.revocable, VestingError::NotRevocable);
+    require!(!vesting.is_revoked(), VestingError::AlreadyRevoked);
+    require!(!vesting.is_complete(), VestingError::InvalidStatus);
+
+    // Freeze the schedule at the current time
+    let now = Clock::get()?.unix_timestamp;
+
+    // Vested-but-unclaimed tokens go to the beneficiary; the unvested rest and
+    // any surplus sent straight to the vault go back to the admin
+    let vault_amount = ctx.accounts.vault.amount;
+    let vested = match vesting.status {
+        VestingStatus::Funded => vesting.claimable(now)?.min(vault_amount),
+        _ => 0,
+    };
+    let unvested = vault_amount
+        .checked_sub(vested)
+        .ok_or(VestingError::Overflow)?;
+
+    vesting.with_signer_seeds(|signer| {
+        // Pay out the vested portion to the beneficiary
+        if vested > 0 {
+            let transfer_accounts = TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: ctx.accounts.vesting_account.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_accounts,
+                signer,
+            );
+
+            token_interface::transfer_checked(cpi_ctx, vested, ctx.accounts.mint.decimals)?;
+        }
+
+        // Return the unvested remainder to the admin
+        if unvested > 0 {
+            let transfer_accounts = TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.admin_token_account.to_account_info(),
+                authority: ctx.accounts.vesting_account.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_accounts,
+                signer,
+            );
+
+            token_interface::transfer_checked(cpi_ctx, unvested, ctx.accounts.mint.decimals)?;
+        }
+
+        Ok(())
+    })?;
+
+    let vesting = &mut ctx.accounts.vesting_account;
+    vesting.released_amount = vesting
+        .released_amount
+        .checked_add(vested)
+        .ok_or(VestingError::Overflow)?;
+    vesting.revoked_at = now;
+    vesting.status = VestingStatus::Revoked;
+
+    emit!(VestingRevoked {
+        vesting: vesting.key(),
+        admin: vesting.admin,
+        beneficiary: vesting.beneficiary,
+        mint: vesting.mint,
+        seed: vesting.seed,
+        vested_amount: vested,
+        returned_amount: unvested,
+        released_amount: vesting.released_amount,
+        revoked_at: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VestingError;
+use crate::events::ClaimDelegateUpdated;
+use crate::state::{Config, VestingAccount, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct SetClaimDelegate<'info> {
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = beneficiary @ VestingError::UnauthorizedBeneficiary,
+        constraint = !vesting_account.has_receipt() @ VestingError::NotAllowedWithReceipt,
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(0) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Register (or clear, with `Pubkey::default()`) the key allowed to sign
+/// `claim` on the beneficiary's behalf. Claimed tokens still go to the
+/// beneficiary's ATA.
+pub fn set_claim_delegate_handler(ctx: Context<SetClaimDelegate>, claim_delegate: Pubkey) -> Result<()> {
+    let vesting = &mut ctx.accounts.vesting_account;
+    vesting.claim_delegate = claim_delegate;
+
+    emit!(ClaimDelegateUpdated {
+        vesting: vesting.key(),
+        beneficiary: vesting.beneficiary,
+        claim_delegate,
+    });
+
+    Ok(())
+}
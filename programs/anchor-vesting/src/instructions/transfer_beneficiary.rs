@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VestingError;
+use crate::events::BeneficiaryTransferred;
+use crate::state::{Config, VestingAccount, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct TransferBeneficiary<'info> {
+    pub beneficiary: Signer<'info>,
+
+    /// Required unless the vesting was created `transferable`
+    pub admin: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        has_one = beneficiary @ VestingError::UnauthorizedBeneficiary,
+        constraint = !vesting_account.has_receipt() @ VestingError::NotAllowedWithReceipt,
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.is_active(0) @ VestingError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Hand the grant to `new_beneficiary`. The PDA keeps signing with
+/// `seed_beneficiary`, so the vesting address and vault do not move. Any claim
+/// delegate set by the previous beneficiary is cleared.
+pub fn transfer_beneficiary_handler(
+    ctx: Context<TransferBeneficiary>,
+    new_beneficiary: Pubkey,
+) -> Result<()> {
+    let vesting = &mut ctx.accounts.vesting_account;
+
+    let admin_cosigned = match &ctx.accounts.admin {
+        Some(admin) => {
+            require_keys_eq!(admin.key(), vesting.admin, VestingError::UnauthorizedAdmin);
+            true
+        }
+        None => false,
+    };
+    require!(vesting.transferable || admin_cosigned, VestingError::NotTransferable);
+
+    let previous_beneficiary = vesting.beneficiary;
+    vesting.beneficiary = new_beneficiary;
+    vesting.claim_delegate = Pubkey::default();
+
+    emit!(BeneficiaryTransferred {
+        vesting: vesting.key(),
+        previous_beneficiary,
+        new_beneficiary,
+        admin_cosigned,
+    });
+
+    Ok(())
+}
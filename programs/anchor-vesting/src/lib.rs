@@ -3,31 +3,149 @@ use anchor_lang::prelude::*;
 declare_id!("BB1JtUxXtmDnb6L5qXUSfuvT18TggYuSLBzfmjoYFnb4");
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::MerkleLeaf;
 
 #[program]
 pub mod anchor_vesting {
     use super::*;
 
-    pub fn create_vesting(
-        ctx: Context<CreateVesting>,
+    pub fn create_vesting(ctx: Context<CreateVesting>, params: CreateVestingParams) -> Result<()> {
+        instructions::create_vesting::create_vesting_handler(ctx, params)
+    }
+
+    pub fn create_and_fund_vesting(
+        ctx: Context<CreateAndFundVesting>,
+        params: CreateVestingParams,
+    ) -> Result<()> {
+        instructions::create_and_fund_vesting::create_and_fund_vesting_handler(ctx, params)
+    }
+
+    pub fn create_vesting_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateVestingBatch<'info>>,
+        entries: Vec<BatchVestingEntry>,
+    ) -> Result<()> {
+        instructions::create_vesting_batch::create_vesting_batch_handler(ctx, entries)
+    }
+
+    pub fn create_merkle_vesting(
+        ctx: Context<CreateMerkleVesting>,
         seed: u64,
+        merkle_root: [u8; 32],
         total_amount: u64,
-        start_time: i64,
-        cliff_time: i64,
-        end_time: i64,
+        clawback_time: i64,
+    ) -> Result<()> {
+        instructions::create_merkle_vesting::create_merkle_vesting_handler(
+            ctx,
+            seed,
+            merkle_root,
+            total_amount,
+            clawback_time,
+        )
+    }
+
+    pub fn claim_merkle(
+        ctx: Context<ClaimMerkle>,
+        leaf: MerkleLeaf,
+        proof: Vec<[u8; 32]>,
+        amount: Option<u64>,
     ) -> Result<()> {
-        instructions::create_vesting::create_vesting_handler(ctx, seed, total_amount, start_time, cliff_time, end_time)
+        instructions::claim_merkle::claim_merkle_handler(ctx, leaf, proof, amount)
+    }
+
+    pub fn close_merkle_vesting(ctx: Context<CloseMerkleVesting>) -> Result<()> {
+        instructions::close_merkle_vesting::close_merkle_vesting_handler(ctx)
+    }
+
+    pub fn close_claim_status(ctx: Context<CloseClaimStatus>) -> Result<()> {
+        instructions::close_merkle_vesting::close_claim_status_handler(ctx)
     }
 
     pub fn deposit(ctx: Context<Deposit>) -> Result<()> {
         instructions::deposit::deposit_handler(ctx)
     }
 
-    pub fn claim(ctx: Context<Claim>) -> Result<()> {
-        instructions::claim::claim_handler(ctx)
+    pub fn claim(ctx: Context<Claim>, amount: Option<u64>) -> Result<()> {
+        instructions::claim::claim_handler(ctx, amount)
+    }
+
+    pub fn claim_to(ctx: Context<ClaimTo>, amount: Option<u64>) -> Result<()> {
+        instructions::claim_to::claim_to_handler(ctx, amount)
+    }
+
+    pub fn crank_claim(ctx: Context<CrankClaim>) -> Result<()> {
+        instructions::crank_claim::crank_claim_handler(ctx)
+    }
+
+    pub fn set_claim_delegate(ctx: Context<SetClaimDelegate>, claim_delegate: Pubkey) -> Result<()> {
+        instructions::set_claim_delegate::set_claim_delegate_handler(ctx, claim_delegate)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin::propose_admin_handler(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::accept_admin_handler(ctx)
+    }
+
+    pub fn transfer_beneficiary(ctx: Context<TransferBeneficiary>, new_beneficiary: Pubkey) -> Result<()> {
+        instructions::transfer_beneficiary::transfer_beneficiary_handler(ctx, new_beneficiary)
+    }
+
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        paused: bool,
+        pause_flags: u8,
+        treasury: Pubkey,
+        creation_fee_lamports: u64,
+        deposit_fee_bps: u16,
+        fee_exempt: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::initialize_config::initialize_config_handler(
+            ctx,
+            paused,
+            pause_flags,
+            treasury,
+            creation_fee_lamports,
+            deposit_fee_bps,
+            fee_exempt,
+        )
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        paused: bool,
+        pause_flags: u8,
+        treasury: Pubkey,
+        creation_fee_lamports: u64,
+        deposit_fee_bps: u16,
+        fee_exempt: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_config::update_config_handler(
+            ctx,
+            paused,
+            pause_flags,
+            treasury,
+            creation_fee_lamports,
+            deposit_fee_bps,
+            fee_exempt,
+        )
+    }
+
+    pub fn revoke(ctx: Context<Revoke>) -> Result<()> {
+        instructions::revoke::revoke_handler(ctx)
+    }
+
+    pub fn close_vesting(ctx: Context<CloseVesting>) -> Result<()> {
+        instructions::close_vesting::close_vesting_handler(ctx)
+    }
+
+    pub fn migrate_vesting(ctx: Context<MigrateVesting>) -> Result<()> {
+        instructions::migrate_vesting::migrate_vesting_handler(ctx)
     }
 }
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VestingError;
+
+/// Seed of the singleton program config PDA
+pub const CONFIG_SEED: &[u8] = b"config";
+
+/// `pause_flags` bits; `paused` stops every instruction regardless
+pub const PAUSE_CREATE: u8 = 1 << 0;
+pub const PAUSE_DEPOSIT: u8 = 1 << 1;
+pub const PAUSE_CLAIM: u8 = 1 << 2;
+
+/// Maximum number of fee-exempt (partner) admins
+pub const MAX_FEE_EXEMPT: usize = 8;
+/// 100% in basis points
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+/// Program-wide switches and protocol fees, managed by the program upgrade authority
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    /// Emergency stop for every vesting instruction
+    pub paused: bool,
+    /// Finer-grained pauses (`PAUSE_CREATE`, `PAUSE_DEPOSIT`, `PAUSE_CLAIM`)
+    pub pause_flags: u8,
+    pub bump: u8,
+    /// Receives the SOL creation fee and owns the token accounts the deposit fee goes to
+    pub treasury: Pubkey,
+    /// Flat SOL fee charged on every vesting creation
+    pub creation_fee_lamports: u64,
+    /// Token fee charged on deposit, in basis points of total_amount, on top of it
+    pub deposit_fee_bps: u16,
+    pub fee_exempt_len: u8,
+    /// Partner admins that pay no fees; only the first `fee_exempt_len` entries are used
+    pub fee_exempt: [Pubkey; MAX_FEE_EXEMPT],
+}
+
+impl Config {
+    /// Whether an instruction guarded by `pause_bits` may run
+    pub fn is_active(&self, pause_bits: u8) -> bool {
+        !self.paused && self.pause_flags & pause_bits == 0
+    }
+
+    /// Replace the fee schedule
+    pub fn set_fees(
+        &mut self,
+        treasury: Pubkey,
+        creation_fee_lamports: u64,
+        deposit_fee_bps: u16,
+        fee_exempt: &[Pubkey],
+    ) -> Result<()> {
+        require!(deposit_fee_bps <= MAX_FEE_BPS, VestingError::InvalidFeeConfig);
+        require!(fee_exempt.len() <= MAX_FEE_EXEMPT, VestingError::InvalidFeeConfig);
+
+        self.treasury = treasury;
+        self.creation_fee_lamports = creation_fee_lamports;
+        self.deposit_fee_bps = deposit_fee_bps;
+        self.fee_exempt_len = fee_exempt.len() as u8;
+        self.fee_exempt = [Pubkey::default(); MAX_FEE_EXEMPT];
+        self.fee_exempt[..fee_exempt.len()].copy_from_slice(fee_exempt);
+        Ok(())
+    }
+
+    pub fn is_fee_exempt(&self, admin: &Pubkey) -> bool {
+        self.fee_exempt[..self.fee_exempt_len as usize].contains(admin)
+    }
+
+    /// SOL fee `admin` pays to create a vesting
+    pub fn creation_fee(&self, admin: &Pubkey) -> u64 {
+        if self.is_fee_exempt(admin) {
+            0
+        } else {
+            self.creation_fee_lamports
+        }
+    }
+
+    /// Token fee `admin` pays on top of depositing `amount` (rounded down)
+    pub fn deposit_fee(&self, admin: &Pubkey, amount: u64) -> Result<u64> {
+        if self.is_fee_exempt(admin) {
+            return Ok(0);
+        }
+        let fee = (amount as u128)
+            .checked_mul(self.deposit_fee_bps as u128)
+            .ok_or(VestingError::Overflow)?
+            / MAX_FEE_BPS as u128;
+        Ok(fee as u64)
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::state::{ScheduleKind, SchedulePoint, VestingAccount, VestingStatus, MAX_SCHEDULE_POINTS};
+
+/// Seed of a merkle distributor PDA: ["merkle_vesting", admin, mint, seed]
+pub const MERKLE_VESTING_SEED: &[u8] = b"merkle_vesting";
+/// Seed of a per-leaf claim status PDA: ["claim_status", merkle_vesting, beneficiary, leaf_hash]
+pub const CLAIM_STATUS_SEED: &[u8] = b"claim_status";
+
+/// Domain-separation prefixes so a leaf can never be passed off as an inner node
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// One vault shared by every recipient committed to in `merkle_root`
+#[account]
+#[derive(InitSpace)]
+pub struct MerkleVesting {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    /// Root of the tree of `MerkleLeaf` hashes
+    pub merkle_root: [u8; 32],
+    pub seed: u64,
+    pub bump: u8,
+    /// Sum of every leaf's total_amount, deposited at creation. The program
+    /// cannot check it against the leaves; the admin must pass the exact sum
+    pub total_amount: u64,
+    /// Tokens claimed so far across all recipients
+    pub released_amount: u64,
+    /// From this time the admin may close the distributor and reclaim whatever
+    /// is unclaimed (0 = only once everything has been claimed)
+    pub clawback_time: i64,
+}
+
+impl MerkleVesting {
+    /// Fully claimed, or past the clawback time
+    pub fn is_settled(&self, now: i64) -> bool {
+        self.released_amount >= self.total_amount
+            || (self.clawback_time != 0 && now >= self.clawback_time)
+    }
+}
+
+/// Per-recipient claim progress, created on the recipient's first claim
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimStatus {
+    /// Hash of the proven leaf (also a PDA seed); zero until the first claim
+    pub leaf_hash: [u8; 32],
+    pub released_amount: u64,
+    pub bump: u8,
+}
+
+/// A recipient's allocation and schedule, as committed to in the merkle tree
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MerkleLeaf {
+    pub beneficiary: Pubkey,
+    pub total_amount: u64,
+    pub start_time: i64,
+    pub cliff_time: i64,
+    pub end_time: i64,
+    pub schedule_kind: ScheduleKind,
+    pub period_seconds: u64,
+    pub schedule_points: Vec<SchedulePoint>,
+    pub cliff_amount: u64,
+    pub accrue_from_cliff: bool,
+}
+
+impl MerkleLeaf {
+    /// sha256(0x00 || borsh(leaf))
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        Ok(hashv(&[LEAF_PREFIX, &self.try_to_vec()?]).to_bytes())
+    }
+
+    /// The leaf's schedule as a funded vesting, so `calculate_released` applies unchanged
+    pub fn to_vesting(&self, released_amount: u64) -> VestingAccount {
+        let mut schedule_points = [SchedulePoint::default(); MAX_SCHEDULE_POINTS];
+        schedule_points[..self.schedule_points.len()].copy_from_slice(&self.schedule_points);
+
+        VestingAccount {
+            beneficiary: self.beneficiary,
+            total_amount: self.total_amount,
+            released_amount,
+            start_time: self.start_time,
+            cliff_time: self.cliff_time,
+            end_time: self.end_time,
+            status: VestingStatus::Funded,
+            schedule_kind: self.schedule_kind,
+            period_seconds: self.period_seconds,
+            schedule_len: self.schedule_points.len() as u8,
+            schedule_points,
+            cliff_amount: self.cliff_amount,
+            accrue_from_cliff: self.accrue_from_cliff,
+            ..Default::default()
+        }
+    }
+}
+
+/// Whether `proof` links `leaf` to `root`. Pairs are hashed in sorted order,
+/// so proofs need no left/right flags.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (a, b) = if node <= *sibling { (&node, sibling) } else { (sibling, &node) };
+        hashv(&[NODE_PREFIX, a, b]).to_bytes()
+    });
+    computed == *root
+}
@@ -1,3 +1,7 @@
+pub mod config;
+pub mod merkle_vesting;
 pub mod vesting_account;
 
+pub use config::*;
+pub use merkle_vesting::*;
 pub use vesting_account::*;
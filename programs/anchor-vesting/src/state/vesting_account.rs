@@ -1,7 +1,50 @@
 use anchor_lang::prelude::*;
 
+/// Capacity of the custom schedule table stored on every vesting account
+pub const MAX_SCHEDULE_POINTS: usize = 8;
+
+/// Current `VestingAccount` layout; the Pinocchio program rejects any other
+pub const VESTING_LAYOUT_VERSION: u8 = 1;
+
+/// Lifecycle of a vesting: Created -> Funded -> Completed, or -> Revoked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub enum VestingStatus {
+    /// Created but not yet funded by `deposit`
+    #[default]
+    Created,
+    /// total_amount deposited; beneficiary may claim
+    Funded,
+    /// Revoked by the admin; schedule frozen at `revoked_at`
+    Revoked,
+    /// Every token has been released to the beneficiary
+    Completed,
+}
+
+/// How tokens are released between the cliff and `end_time`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub enum ScheduleKind {
+    /// Continuous per-second release from start_time to end_time
+    #[default]
+    Linear,
+    /// Equal steps at every `period_seconds` boundary counted from start_time;
+    /// the rounding remainder is released with the final step
+    Periodic,
+    /// Custom `schedule_points` table, interpolated linearly between points
+    CustomLinear,
+    /// Custom `schedule_points` table, each point's amount unlocks at its timestamp
+    CustomStep,
+}
+
+/// One point of a custom schedule: `amount` tokens released in total by `timestamp`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct SchedulePoint {
+    pub timestamp: i64,
+    /// Cumulative amount released at `timestamp`
+    pub amount: u64,
+}
+
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct VestingAccount {
     /// The admin (Project Owner) who created this vesting
     pub admin: Pubkey,
@@ -23,12 +66,71 @@ pub struct VestingAccount {
     pub seed: u64,
     /// PDA bump seed
     pub bump: u8,
+    /// Whether the admin may revoke this vesting and reclaim unvested tokens
+    pub revocable: bool,
+    /// Revocation time (unix timestamp) - 0 while the vesting is active
+    pub revoked_at: i64,
+    /// Funding lifecycle status (gates every instruction instead of vault balance)
+    pub status: VestingStatus,
+    /// Address derived without the admin key (migrated pre-upgrade account)
+    pub legacy_seeds: bool,
+    /// Release curve between the cliff and end_time
+    pub schedule_kind: ScheduleKind,
+    /// Step length in seconds for `Periodic` schedules (0 for `Linear`)
+    pub period_seconds: u64,
+    /// Number of used entries in `schedule_points` (custom schedules only)
+    pub schedule_len: u8,
+    /// Custom schedule table; entries past `schedule_len` are zeroed
+    pub schedule_points: [SchedulePoint; MAX_SCHEDULE_POINTS],
+    /// Tokens unlocked at once when the cliff is reached (Linear/Periodic only)
+    pub cliff_amount: u64,
+    /// Accrue the rest from cliff_time instead of start_time (Linear/Periodic only)
+    pub accrue_from_cliff: bool,
+    /// Anyone may push claimable tokens to the beneficiary's ATA via `crank_claim`
+    pub allow_permissionless_claim: bool,
+    /// May sign `claim` on the beneficiary's behalf (`Pubkey::default()` = none)
+    pub claim_delegate: Pubkey,
+    /// Proposed new admin awaiting `accept_admin` (`Pubkey::default()` = none)
+    pub pending_admin: Pubkey,
+    /// Admin the PDA was derived with; unchanged by admin transfers
+    pub seed_admin: Pubkey,
+    /// Beneficiary may hand the grant to a new wallet without an admin co-signature
+    pub transferable: bool,
+    /// Beneficiary the PDA was derived with; unchanged by beneficiary transfers
+    pub seed_beneficiary: Pubkey,
+    /// Token-2022 NFT whose holder claims instead of a fixed beneficiary (`Pubkey::default()` = none)
+    pub receipt_mint: Pubkey,
+    /// `VESTING_LAYOUT_VERSION` at creation or migration
+    pub layout_version: u8,
+}
+
+/// Pre-upgrade `VestingAccount` layout, read only by `migrate_vesting`
+#[derive(AnchorDeserialize)]
+pub struct LegacyVestingAccount {
+    pub admin: Pubkey,
+    pub beneficiary: Pubkey,
+    pub mint: Pubkey,
+    pub total_amount: u64,
+    pub released_amount: u64,
+    pub start_time: i64,
+    pub cliff_time: i64,
+    pub end_time: i64,
+    pub seed: u64,
+    pub bump: u8,
+}
+
+impl LegacyVestingAccount {
+    /// Serialized size, excluding the 8-byte Anchor discriminator
+    pub const SPACE: usize = 32 * 3 + 8 * 6 + 1;
 }
 
 impl VestingAccount {
     /// Calculate the total amount of tokens that should be released by `now`.
     /// Uses u128 intermediate math to prevent overflow.
     pub fn calculate_released(&self, now: i64) -> Result<u64> {
+        // Revoked: the schedule is frozen at the revocation time
+        let now = if self.is_revoked() { now.min(self.revoked_at) } else { now };
+
         // Before cliff: nothing released
         if now < self.cliff_time {
             return Ok(0);
@@ -39,20 +141,114 @@ impl VestingAccount {
             return Ok(self.total_amount);
         }
 
-        // Linear release between start_time and end_time
-        let elapsed = (now - self.start_time) as u128;
-        let duration = (self.end_time - self.start_time) as u128;
-        let total = self.total_amount as u128;
+        // cliff_amount unlocks at the cliff; the rest accrues from the accrual start
+        let accrual_start = self.accrual_start();
+        let elapsed = (now - accrual_start) as u128;
+        let duration = (self.end_time - accrual_start) as u128;
+        let accruing = (self.total_amount - self.cliff_amount) as u128;
+        let cliff_amount = self.cliff_amount as u128;
 
-        let released = total
-            .checked_mul(elapsed)
-            .ok_or(error!(crate::errors::VestingError::Overflow))?
-            .checked_div(duration)
-            .ok_or(error!(crate::errors::VestingError::Overflow))?;
+        let released = match self.schedule_kind {
+            // Linear release between the accrual start and end_time
+            ScheduleKind::Linear => cliff_amount + accruing
+                .checked_mul(elapsed)
+                .ok_or(error!(crate::errors::VestingError::Overflow))?
+                .checked_div(duration)
+                .ok_or(error!(crate::errors::VestingError::Overflow))?,
+            // Equal steps per completed period; the last step (at end_time) is
+            // handled above and picks up the remainder
+            ScheduleKind::Periodic => {
+                let period = self.period_seconds as u128;
+                let periods = duration.div_ceil(period);
+                cliff_amount + (accruing / periods) * (elapsed / period)
+            }
+            ScheduleKind::CustomLinear | ScheduleKind::CustomStep => {
+                self.custom_released(now) as u128
+            }
+        };
 
         Ok(released as u64)
     }
 
+    /// Time from which the non-cliff portion accrues
+    pub fn accrual_start(&self) -> i64 {
+        if self.accrue_from_cliff {
+            self.cliff_time
+        } else {
+            self.start_time
+        }
+    }
+
+    /// Amount released by a custom schedule table at `now` (after the cliff)
+    fn custom_released(&self, now: i64) -> u64 {
+        let points = &self.schedule_points[..self.schedule_len as usize];
+
+        // Last point reached by `now`
+        let Some(i) = points.iter().rposition(|p| p.timestamp <= now) else {
+            return 0;
+        };
+        let current = points[i];
+
+        match (self.schedule_kind, points.get(i + 1)) {
+            (ScheduleKind::CustomLinear, Some(next)) => {
+                // next.timestamp > now >= current.timestamp, so span > 0
+                let span = (next.timestamp - current.timestamp) as u128;
+                let step = (next.amount - current.amount) as u128;
+                let elapsed = (now - current.timestamp) as u128;
+                current.amount + (step * elapsed / span) as u64
+            }
+            _ => current.amount,
+        }
+    }
+
+    /// Whether `key` may sign `claim`: the beneficiary or its claim delegate
+    pub fn is_claimer(&self, key: &Pubkey) -> bool {
+        *key == self.beneficiary
+            || (self.claim_delegate != Pubkey::default() && *key == self.claim_delegate)
+    }
+
+    /// Whether the position is represented by an NFT receipt
+    pub fn has_receipt(&self) -> bool {
+        self.receipt_mint != Pubkey::default()
+    }
+
+    /// Whether the admin has revoked this vesting
+    pub fn is_revoked(&self) -> bool {
+        self.status == VestingStatus::Revoked
+    }
+
+    /// Whether no further tokens can ever be released from this vesting
+    pub fn is_complete(&self) -> bool {
+        matches!(self.status, VestingStatus::Completed | VestingStatus::Revoked)
+    }
+
+    /// Run `f` with the PDA signer seeds matching this account's address.
+    /// Current seeds: ["vesting", admin, beneficiary, mint, seed];
+    /// legacy seeds (migrated accounts): ["vesting", beneficiary, mint, seed].
+    pub fn with_signer_seeds(&self, f: impl FnOnce(&[&[&[u8]]]) -> Result<()>) -> Result<()> {
+        let seed_bytes = self.seed.to_le_bytes();
+        let bump_bytes = [self.bump];
+
+        if self.legacy_seeds {
+            f(&[&[
+                b"vesting",
+                self.seed_beneficiary.as_ref(),
+                self.mint.as_ref(),
+                &seed_bytes,
+                &bump_bytes,
+            ]])
+        } else {
+            f(&[&[
+                b"vesting",
+                self.seed_admin.as_ref(),
+                self.seed_beneficiary.as_ref(),
+                self.mint.as_ref(),
+                &seed_bytes,
+                &bump_bytes,
+            ]])
+        }
+    }
+
     /// Calculate the amount currently claimable (released but not yet claimed)
     pub fn claimable(&self, now: i64) -> Result<u64> {
         let total_released = self.calculate_released(now)?;
@@ -22,6 +22,9 @@ anchor build
 echo "==> Building Pinocchio program..."
 (cd pinocchio-vesting && cargo build-sbf)
 
+echo "==> Running Pinocchio Rust tests..."
+(cd pinocchio-vesting && cargo test)
+
 # ──────────────────────────────────────────────
 # 2. Start test validator
 # ──────────────────────────────────────────────
@@ -1,23 +1,38 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
+import { IdlTypes, Program } from "@coral-xyz/anchor";
 import { AnchorVesting } from "../target/types/anchor_vesting";
 import {
   Keypair,
   PublicKey,
   SystemProgram,
   LAMPORTS_PER_SOL,
+  Transaction,
+  sendAndConfirmTransaction,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   createMint,
   mintTo,
   getAssociatedTokenAddress,
   getAccount,
   getOrCreateAssociatedTokenAccount,
+  createAccount,
+  transfer,
+  transferChecked,
+  getMint,
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountIdempotentInstruction,
+  getMintCloseAuthority,
 } from "@solana/spl-token";
 import { expect } from "chai";
 import BN from "bn.js";
+import { createHash } from "crypto";
+
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
 
 describe("anchor-vesting", () => {
   const provider = anchor.AnchorProvider.env();
@@ -49,11 +64,121 @@ describe("anchor-vesting", () => {
   let cliffTime: number;
   let endTime: number;
 
+  // ─────────────────────────────────────────
+  // Shared helpers
+  // ─────────────────────────────────────────
+
+  type CreateVestingParams = IdlTypes<AnchorVesting>["createVestingParams"];
+  /** The fields every grant sets; the rest default to a linear, irrevocable grant */
+  type GrantParams = Pick<
+    CreateVestingParams,
+    "seed" | "totalAmount" | "startTime" | "cliffTime" | "endTime"
+  > &
+    Partial<CreateVestingParams>;
+
+  /** Block time of the latest slot, or wall-clock time if unavailable */
+  const clusterNow = async () => {
+    const slot = await connection.getSlot();
+    const blockTime = await connection.getBlockTime(slot);
+    return blockTime || Math.floor(Date.now() / 1000);
+  };
+
+  /** Vesting PDA of `admin` for `owner` over `mint` */
+  const derivePda = (vestingSeed: BN, owner: PublicKey = beneficiary.publicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vesting"),
+        admin.publicKey.toBuffer(),
+        owner.toBuffer(),
+        mint.toBuffer(),
+        vestingSeed.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    )[0];
+
+  const withDefaults = (params: GrantParams): CreateVestingParams => ({
+    revocable: false,
+    scheduleKind: { linear: {} },
+    periodSeconds: new BN(0),
+    schedulePoints: [],
+    cliffAmount: new BN(0),
+    accrueFromCliff: false,
+    allowPermissionlessClaim: false,
+    transferable: false,
+    ...params,
+  });
+
+  /** Accounts shared by create_vesting and create_and_fund_vesting */
+  const createAccounts = async (vestingSeed: BN) => {
+    const pda = derivePda(vestingSeed);
+    const pdaVault = await getAssociatedTokenAddress(mint, pda, true);
+    const accounts = {
+      admin: admin.publicKey,
+      beneficiary: beneficiary.publicKey,
+      mint,
+      vestingAccount: pda,
+      vault: pdaVault,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    };
+    return { pda, pdaVault, accounts };
+  };
+
+  /** `create_vesting` from `admin` to `beneficiary`, left unfunded */
+  const createGrant = async (
+    params: GrantParams,
+    extraAccounts: { [name: string]: PublicKey | null } = {},
+    extraSigners: Keypair[] = []
+  ) => {
+    const { pda, pdaVault, accounts } = await createAccounts(params.seed);
+    await program.methods
+      .createVesting(withDefaults(params))
+      .accountsPartial({ ...accounts, ...extraAccounts })
+      .signers([admin, ...extraSigners])
+      .rpc();
+    return { pda, pdaVault };
+  };
+
+  /** `create_and_fund_vesting` from `admin` to `beneficiary`, paid from `adminAta` */
+  const createAndFund = async (
+    params: GrantParams,
+    extraAccounts: { [name: string]: PublicKey | null } = {}
+  ) => {
+    const { pda, pdaVault, accounts } = await createAccounts(params.seed);
+    await program.methods
+      .createAndFundVesting(withDefaults(params))
+      .accountsPartial({ ...accounts, adminTokenAccount: adminAta, ...extraAccounts })
+      .signers([admin])
+      .rpc();
+    return { pda, pdaVault };
+  };
+
+  /** `claim` by the beneficiary into `beneficiaryAta`; `null` claims everything claimable */
+  const claimFrom = (pda: PublicKey, pdaVault: PublicKey, amount: BN | null = null) =>
+    program.methods
+      .claim(amount)
+      .accountsPartial({
+        claimer: beneficiary.publicKey,
+        beneficiary: beneficiary.publicKey,
+        mint,
+        vestingAccount: pda,
+        vault: pdaVault,
+        beneficiaryTokenAccount: beneficiaryAta,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([beneficiary])
+      .rpc();
+
   // ─────────────────────────────────────────
   // Setup
   // ─────────────────────────────────────────
 
   before(async () => {
+    await ensureAnchorConfig(program);
+
     // Airdrop SOL to test actors
     const airdropAmount = 10 * LAMPORTS_PER_SOL;
     await Promise.all([
@@ -92,6 +217,7 @@ describe("anchor-vesting", () => {
     [vestingPda, vestingBump] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("vesting"),
+        admin.publicKey.toBuffer(),
         beneficiary.publicKey.toBuffer(),
         mint.toBuffer(),
         seed.toArrayLike(Buffer, "le", 8),
@@ -106,9 +232,7 @@ describe("anchor-vesting", () => {
     );
 
     // Set time parameters: start=now, cliff=+30s, end=+60s
-    const slot = await connection.getSlot();
-    const blockTime = await connection.getBlockTime(slot);
-    const now = blockTime || Math.floor(Date.now() / 1000);
+    const now = await clusterNow();
     startTime = now - 10; // started 10s ago
     cliffTime = now + 20; // cliff in 20s
     endTime = now + 60; // end in 60s
@@ -121,13 +245,21 @@ describe("anchor-vesting", () => {
   describe("TR-1: Happy Path", () => {
     it("T-01: creates vesting with valid parameters", async () => {
       const tx = await program.methods
-        .createVesting(
+        .createVesting({
           seed,
-          new BN(totalAmount),
-          new BN(startTime),
-          new BN(cliffTime),
-          new BN(endTime)
-        )
+          totalAmount: new BN(totalAmount),
+          startTime: new BN(startTime),
+          cliffTime: new BN(cliffTime),
+          endTime: new BN(endTime),
+          revocable: false,
+          scheduleKind: { linear: {} },
+          periodSeconds: new BN(0),
+          schedulePoints: [],
+          cliffAmount: new BN(0),
+          accrueFromCliff: false,
+          allowPermissionlessClaim: false,
+          transferable: false,
+        })
         .accountsPartial({
           admin: admin.publicKey,
           beneficiary: beneficiary.publicKey,
@@ -186,20 +318,7 @@ describe("anchor-vesting", () => {
     it("T-10: claim fails before cliff", async () => {
       // Cliff hasn't passed yet in real time (cliffTime is ~20s from setup)
       try {
-        await program.methods
-          .claim()
-          .accountsPartial({
-            beneficiary: beneficiary.publicKey,
-            mint,
-            vestingAccount: vestingPda,
-            vault,
-            beneficiaryTokenAccount: beneficiaryAta,
-            systemProgram: SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          })
-          .signers([beneficiary])
-          .rpc();
+        await claimFrom(vestingPda, vault);
         expect.fail("Should have failed: cliff not reached");
       } catch (err: any) {
         expect(err.error?.errorCode?.code).to.equal("NothingToClaim");
@@ -218,42 +337,19 @@ describe("anchor-vesting", () => {
     let secVault: PublicKey;
 
     before(async () => {
-      [secVestingPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          secSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
+      secVestingPda = derivePda(secSeed);
       secVault = await getAssociatedTokenAddress(mint, secVestingPda, true);
 
       // Create a second vesting for security tests
-      const slot = await connection.getSlot();
-      const blockTime = await connection.getBlockTime(slot);
-      const now = blockTime || Math.floor(Date.now() / 1000);
-
-      await program.methods
-        .createVesting(
-          secSeed,
-          new BN(totalAmount),
-          new BN(now - 10),
-          new BN(now + 20),
-          new BN(now + 60)
-        )
-        .accountsPartial({
-          admin: admin.publicKey,
-          beneficiary: beneficiary.publicKey,
-          mint,
-          vestingAccount: secVestingPda,
-          vault: secVault,
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        })
-        .signers([admin])
-        .rpc();
+      const now = await clusterNow();
+
+      await createGrant({
+        seed: secSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(now - 10),
+        cliffTime: new BN(now + 20),
+        endTime: new BN(now + 60),
+      });
     });
 
     it("T-20: non-admin cannot deposit", async () => {
@@ -304,8 +400,9 @@ describe("anchor-vesting", () => {
       );
       try {
         await program.methods
-          .claim()
+          .claim(null)
           .accountsPartial({
+            claimer: unauthorizedUser.publicKey,
             beneficiary: unauthorizedUser.publicKey,
             mint,
             vestingAccount: secVestingPda,
@@ -367,50 +464,20 @@ describe("anchor-vesting", () => {
     let expiredEnd: number;
 
     before(async () => {
-      const slot = await connection.getSlot();
-      const blockTime = await connection.getBlockTime(slot);
-      const now = blockTime || Math.floor(Date.now() / 1000);
+      const now = await clusterNow();
 
       // Vesting with cliff already passed, end in future
       pastCliffStart = now - 100;
       pastCliffCliff = now - 50;
       pastCliffEnd = now + 100;
 
-      [pastCliffPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          pastCliffSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-      pastCliffVault = await getAssociatedTokenAddress(
-        mint,
-        pastCliffPda,
-        true
-      );
-
-      await program.methods
-        .createVesting(
-          pastCliffSeed,
-          new BN(totalAmount),
-          new BN(pastCliffStart),
-          new BN(pastCliffCliff),
-          new BN(pastCliffEnd)
-        )
-        .accountsPartial({
-          admin: admin.publicKey,
-          beneficiary: beneficiary.publicKey,
-          mint,
-          vestingAccount: pastCliffPda,
-          vault: pastCliffVault,
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        })
-        .signers([admin])
-        .rpc();
+      ({ pda: pastCliffPda, pdaVault: pastCliffVault } = await createGrant({
+        seed: pastCliffSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(pastCliffStart),
+        cliffTime: new BN(pastCliffCliff),
+        endTime: new BN(pastCliffEnd),
+      }));
 
       await program.methods
         .deposit()
@@ -431,41 +498,13 @@ describe("anchor-vesting", () => {
       expiredCliff = now - 150;
       expiredEnd = now - 50;
 
-      [fullyExpiredPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          fullyExpiredSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-      fullyExpiredVault = await getAssociatedTokenAddress(
-        mint,
-        fullyExpiredPda,
-        true
-      );
-
-      await program.methods
-        .createVesting(
-          fullyExpiredSeed,
-          new BN(totalAmount),
-          new BN(expiredStart),
-          new BN(expiredCliff),
-          new BN(expiredEnd)
-        )
-        .accountsPartial({
-          admin: admin.publicKey,
-          beneficiary: beneficiary.publicKey,
-          mint,
-          vestingAccount: fullyExpiredPda,
-          vault: fullyExpiredVault,
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        })
-        .signers([admin])
-        .rpc();
+      ({ pda: fullyExpiredPda, pdaVault: fullyExpiredVault } = await createGrant({
+        seed: fullyExpiredSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(expiredStart),
+        cliffTime: new BN(expiredCliff),
+        endTime: new BN(expiredEnd),
+      }));
 
       await program.methods
         .deposit()
@@ -489,8 +528,9 @@ describe("anchor-vesting", () => {
       );
 
       await program.methods
-        .claim()
+        .claim(null)
         .accountsPartial({
+          claimer: beneficiary.publicKey,
           beneficiary: beneficiary.publicKey,
           mint,
           vestingAccount: pastCliffPda,
@@ -521,8 +561,9 @@ describe("anchor-vesting", () => {
       );
 
       await program.methods
-        .claim()
+        .claim(null)
         .accountsPartial({
+          claimer: beneficiary.publicKey,
           beneficiary: beneficiary.publicKey,
           mint,
           vestingAccount: fullyExpiredPda,
@@ -561,8 +602,9 @@ describe("anchor-vesting", () => {
       await new Promise((r) => setTimeout(r, 2000));
 
       await program.methods
-        .claim()
+        .claim(null)
         .accountsPartial({
+          claimer: beneficiary.publicKey,
           beneficiary: beneficiary.publicKey,
           mint,
           vestingAccount: pastCliffPda,
@@ -592,9 +634,7 @@ describe("anchor-vesting", () => {
 
   describe("TR-2: Time Logic (extended)", () => {
     it("T-11: claim at cliff moment yields correct partial amount", async () => {
-      const slot = await connection.getSlot();
-      const blockTime = await connection.getBlockTime(slot);
-      const now = blockTime || Math.floor(Date.now() / 1000);
+      const now = await clusterNow();
 
       // Cliff just passed: start far back, cliff = now - 1, end far future
       const cliffSeed = new BN(600);
@@ -602,41 +642,13 @@ describe("anchor-vesting", () => {
       const cliffCliff = now - 1;
       const cliffEnd = now + 100;
 
-      const [cliffPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          cliffSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-      const cliffVault = await getAssociatedTokenAddress(
-        mint,
-        cliffPda,
-        true
-      );
-
-      await program.methods
-        .createVesting(
-          cliffSeed,
-          new BN(totalAmount),
-          new BN(cliffStart),
-          new BN(cliffCliff),
-          new BN(cliffEnd)
-        )
-        .accountsPartial({
-          admin: admin.publicKey,
-          beneficiary: beneficiary.publicKey,
-          mint,
-          vestingAccount: cliffPda,
-          vault: cliffVault,
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        })
-        .signers([admin])
-        .rpc();
+      const { pda: cliffPda, pdaVault: cliffVault } = await createGrant({
+        seed: cliffSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(cliffStart),
+        cliffTime: new BN(cliffCliff),
+        endTime: new BN(cliffEnd),
+      });
 
       await program.methods
         .deposit()
@@ -658,8 +670,9 @@ describe("anchor-vesting", () => {
       );
 
       await program.methods
-        .claim()
+        .claim(null)
         .accountsPartial({
+          claimer: beneficiary.publicKey,
           beneficiary: beneficiary.publicKey,
           mint,
           vestingAccount: cliffPda,
@@ -683,43 +696,17 @@ describe("anchor-vesting", () => {
     });
 
     it("T-14: second claim after full release fails with NothingToClaim", async () => {
-      const slot = await connection.getSlot();
-      const blockTime = await connection.getBlockTime(slot);
-      const now = blockTime || Math.floor(Date.now() / 1000);
+      const now = await clusterNow();
 
       const endSeed = new BN(601);
-      const [endPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          endSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-      const endVault = await getAssociatedTokenAddress(mint, endPda, true);
-
       // Fully expired vesting
-      await program.methods
-        .createVesting(
-          endSeed,
-          new BN(totalAmount),
-          new BN(now - 200),
-          new BN(now - 150),
-          new BN(now - 50)
-        )
-        .accountsPartial({
-          admin: admin.publicKey,
-          beneficiary: beneficiary.publicKey,
-          mint,
-          vestingAccount: endPda,
-          vault: endVault,
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        })
-        .signers([admin])
-        .rpc();
+      const { pda: endPda, pdaVault: endVault } = await createGrant({
+        seed: endSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(now - 200),
+        cliffTime: new BN(now - 150),
+        endTime: new BN(now - 50),
+      });
 
       await program.methods
         .deposit()
@@ -742,8 +729,9 @@ describe("anchor-vesting", () => {
 
       // First claim: should succeed and claim all
       await program.methods
-        .claim()
+        .claim(null)
         .accountsPartial({
+          claimer: beneficiary.publicKey,
           beneficiary: beneficiary.publicKey,
           mint,
           vestingAccount: endPda,
@@ -762,8 +750,9 @@ describe("anchor-vesting", () => {
       // Second claim: should fail with NothingToClaim
       try {
         await program.methods
-          .claim()
+          .claim(null)
           .accountsPartial({
+            claimer: beneficiary.publicKey,
             beneficiary: beneficiary.publicKey,
             mint,
             vestingAccount: endPda,
@@ -790,45 +779,15 @@ describe("anchor-vesting", () => {
   describe("TR-3: Security (extended)", () => {
     it("T-25: claim before deposit fails with NotFunded", async () => {
       const noDepSeed = new BN(700);
-      const slot = await connection.getSlot();
-      const blockTime = await connection.getBlockTime(slot);
-      const now = blockTime || Math.floor(Date.now() / 1000);
-
-      const [noDepPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          noDepSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-      const noDepVault = await getAssociatedTokenAddress(
-        mint,
-        noDepPda,
-        true
-      );
+      const now = await clusterNow();
 
-      await program.methods
-        .createVesting(
-          noDepSeed,
-          new BN(totalAmount),
-          new BN(now - 200),
-          new BN(now - 100),
-          new BN(now - 50)
-        )
-        .accountsPartial({
-          admin: admin.publicKey,
-          beneficiary: beneficiary.publicKey,
-          mint,
-          vestingAccount: noDepPda,
-          vault: noDepVault,
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        })
-        .signers([admin])
-        .rpc();
+      const { pda: noDepPda, pdaVault: noDepVault } = await createGrant({
+        seed: noDepSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(now - 200),
+        cliffTime: new BN(now - 100),
+        endTime: new BN(now - 50),
+      });
 
       // Do NOT deposit, try to claim immediately
       const beneficiaryTokenAcc = await getAssociatedTokenAddress(
@@ -838,8 +797,9 @@ describe("anchor-vesting", () => {
 
       try {
         await program.methods
-          .claim()
+          .claim(null)
           .accountsPartial({
+            claimer: beneficiary.publicKey,
             beneficiary: beneficiary.publicKey,
             mint,
             vestingAccount: noDepPda,
@@ -856,6 +816,44 @@ describe("anchor-vesting", () => {
         expect(err.error?.errorCode?.code).to.equal("NotFunded");
       }
     });
+
+    it("T-26: lamports sent to the PDA and a pre-created vault do not block create_vesting", async () => {
+      const griefSeed = new BN(701);
+      const griefPda = derivePda(griefSeed);
+      const griefVault = getAssociatedTokenAddressSync(mint, griefPda, true);
+
+      // A third party front-runs the grant: funds the PDA and creates its vault
+      const griefer = Keypair.generate();
+      await airdropSol(connection, griefer.publicKey, LAMPORTS_PER_SOL);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: griefer.publicKey,
+            toPubkey: griefPda,
+            lamports: 1_000_000,
+          }),
+          createAssociatedTokenAccountIdempotentInstruction(
+            griefer.publicKey,
+            griefVault,
+            griefPda,
+            mint
+          )
+        ),
+        [griefer]
+      );
+
+      await createGrant({
+        seed: griefSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(startTime),
+        cliffTime: new BN(cliffTime),
+        endTime: new BN(endTime),
+      });
+
+      const vesting = await program.account.vestingAccount.fetch(griefPda);
+      expect(vesting.totalAmount.toNumber()).to.equal(totalAmount);
+    });
   });
 
   // ─────────────────────────────────────────
@@ -865,38 +863,14 @@ describe("anchor-vesting", () => {
   describe("TR-4: Boundary", () => {
     it("T-30: total_amount = 0 fails", async () => {
       const badSeed = new BN(200);
-      const [badPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          badSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-      const badVault = await getAssociatedTokenAddress(mint, badPda, true);
-
       try {
-        await program.methods
-          .createVesting(
-            badSeed,
-            new BN(0), // zero amount
-            new BN(startTime),
-            new BN(cliffTime),
-            new BN(endTime)
-          )
-          .accountsPartial({
-            admin: admin.publicKey,
-            beneficiary: beneficiary.publicKey,
-            mint,
-            vestingAccount: badPda,
-            vault: badVault,
-            systemProgram: SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          })
-          .signers([admin])
-          .rpc();
+        await createGrant({
+          seed: badSeed,
+          totalAmount: new BN(0), // zero amount
+          startTime: new BN(startTime),
+          cliffTime: new BN(cliffTime),
+          endTime: new BN(endTime),
+        });
         expect.fail("Should have failed: zero amount");
       } catch (err: any) {
         expect(err.error?.errorCode?.code).to.equal("InvalidAmount");
@@ -905,38 +879,14 @@ describe("anchor-vesting", () => {
 
     it("T-31: start_time > cliff_time fails", async () => {
       const badSeed = new BN(201);
-      const [badPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          badSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-      const badVault = await getAssociatedTokenAddress(mint, badPda, true);
-
       try {
-        await program.methods
-          .createVesting(
-            badSeed,
-            new BN(totalAmount),
-            new BN(cliffTime + 100), // start > cliff
-            new BN(cliffTime),
-            new BN(endTime)
-          )
-          .accountsPartial({
-            admin: admin.publicKey,
-            beneficiary: beneficiary.publicKey,
-            mint,
-            vestingAccount: badPda,
-            vault: badVault,
-            systemProgram: SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          })
-          .signers([admin])
-          .rpc();
+        await createGrant({
+          seed: badSeed,
+          totalAmount: new BN(totalAmount),
+          startTime: new BN(cliffTime + 100), // start > cliff
+          cliffTime: new BN(cliffTime),
+          endTime: new BN(endTime),
+        });
         expect.fail("Should have failed: invalid time range");
       } catch (err: any) {
         expect(err.error?.errorCode?.code).to.equal("InvalidTimeRange");
@@ -945,38 +895,14 @@ describe("anchor-vesting", () => {
 
     it("T-32: cliff_time > end_time fails", async () => {
       const badSeed = new BN(202);
-      const [badPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          badSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-      const badVault = await getAssociatedTokenAddress(mint, badPda, true);
-
       try {
-        await program.methods
-          .createVesting(
-            badSeed,
-            new BN(totalAmount),
-            new BN(startTime),
-            new BN(endTime + 100), // cliff > end
-            new BN(endTime)
-          )
-          .accountsPartial({
-            admin: admin.publicKey,
-            beneficiary: beneficiary.publicKey,
-            mint,
-            vestingAccount: badPda,
-            vault: badVault,
-            systemProgram: SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          })
-          .signers([admin])
-          .rpc();
+        await createGrant({
+          seed: badSeed,
+          totalAmount: new BN(totalAmount),
+          startTime: new BN(startTime),
+          cliffTime: new BN(endTime + 100), // cliff > end
+          endTime: new BN(endTime),
+        });
         expect.fail("Should have failed: invalid time range");
       } catch (err: any) {
         expect(err.error?.errorCode?.code).to.equal("InvalidTimeRange");
@@ -985,39 +911,15 @@ describe("anchor-vesting", () => {
 
     it("T-33: start_time = end_time fails", async () => {
       const badSeed = new BN(203);
-      const [badPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          badSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-      const badVault = await getAssociatedTokenAddress(mint, badPda, true);
-
       const sameTime = startTime;
       try {
-        await program.methods
-          .createVesting(
-            badSeed,
-            new BN(totalAmount),
-            new BN(sameTime),
-            new BN(sameTime), // cliff = start = end
-            new BN(sameTime)
-          )
-          .accountsPartial({
-            admin: admin.publicKey,
-            beneficiary: beneficiary.publicKey,
-            mint,
-            vestingAccount: badPda,
-            vault: badVault,
-            systemProgram: SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          })
-          .signers([admin])
-          .rpc();
+        await createGrant({
+          seed: badSeed,
+          totalAmount: new BN(totalAmount),
+          startTime: new BN(sameTime),
+          cliffTime: new BN(sameTime), // cliff = start = end
+          endTime: new BN(sameTime),
+        });
         expect.fail("Should have failed: start = end");
       } catch (err: any) {
         expect(err.error?.errorCode?.code).to.equal("InvalidTimeRange");
@@ -1026,129 +928,2106 @@ describe("anchor-vesting", () => {
 
     it("T-34: total_amount = 1 (minimum) succeeds", async () => {
       const minSeed = new BN(204);
-      const [minPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          minSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-      const minVault = await getAssociatedTokenAddress(mint, minPda, true);
+      const { pda: minPda } = await createGrant({
+        seed: minSeed,
+        totalAmount: new BN(1), // minimum amount
+        startTime: new BN(startTime),
+        cliffTime: new BN(cliffTime),
+        endTime: new BN(endTime),
+      });
 
-      await program.methods
-        .createVesting(
-          minSeed,
-          new BN(1), // minimum amount
-          new BN(startTime),
-          new BN(cliffTime),
-          new BN(endTime)
-        )
-        .accountsPartial({
-          admin: admin.publicKey,
-          beneficiary: beneficiary.publicKey,
+      const vesting = await program.account.vestingAccount.fetch(minPda);
+      expect(vesting.totalAmount.toNumber()).to.equal(1);
+    });
+
+    it("T-35: large total_amount does not overflow (u128 safe math)", async () => {
+      const bigSeed = new BN(205);
+
+      // Use a large amount near u64 max (but representable in BN)
+      // u64::MAX = 18446744073709551615, use a large but valid value
+      const largeAmount = new BN("1000000000000000000"); // 10^18
+
+      const { pda: bigPda } = await createGrant({
+        seed: bigSeed,
+        totalAmount: largeAmount,
+        startTime: new BN(startTime),
+        cliffTime: new BN(cliffTime),
+        endTime: new BN(endTime),
+      });
+
+      const vesting = await program.account.vestingAccount.fetch(bigPda);
+      expect(vesting.totalAmount.toString()).to.equal(
+        largeAmount.toString()
+      );
+    });
+
+    it("T-05: multiple vestings for same beneficiary+mint", async () => {
+      const secondSeed = new BN(300);
+      const { pda: secondPda } = await createGrant({
+        seed: secondSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(startTime),
+        cliffTime: new BN(cliffTime),
+        endTime: new BN(endTime),
+      });
+
+      // Both vestings should exist
+      const v1 = await program.account.vestingAccount.fetch(vestingPda);
+      const v2 = await program.account.vestingAccount.fetch(secondPda);
+      expect(v1.seed.toNumber()).to.equal(1);
+      expect(v2.seed.toNumber()).to.equal(300);
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-6: Revocation
+  // ─────────────────────────────────────────
+
+  describe("TR-6: Revocation", () => {
+    const revocableSeed = new BN(600);
+    const irrevocableSeed = new BN(601);
+    let revocablePda: PublicKey;
+    let revocableVault: PublicKey;
+    let irrevocablePda: PublicKey;
+    let irrevocableVault: PublicKey;
+
+    function revokeAccounts(pda: PublicKey, pdaVault: PublicKey, signer: PublicKey) {
+      return {
+        admin: signer,
+        beneficiary: beneficiary.publicKey,
+        mint,
+        vestingAccount: pda,
+        vault: pdaVault,
+        beneficiaryTokenAccount: beneficiaryAta,
+        adminTokenAccount: adminAta,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      };
+    }
+
+    before(async () => {
+      const now = await clusterNow();
+      const grant = (vestingSeed: BN, revocable: boolean) =>
+        createAndFund({
+          seed: vestingSeed,
+          totalAmount: new BN(totalAmount),
+          startTime: new BN(now - 100),
+          cliffTime: new BN(now - 50),
+          endTime: new BN(now + 100),
+          revocable,
+        });
+
+      ({ pda: revocablePda, pdaVault: revocableVault } = await grant(revocableSeed, true));
+      ({ pda: irrevocablePda, pdaVault: irrevocableVault } = await grant(irrevocableSeed, false));
+    });
+
+    it("T-50: non-admin cannot revoke", async () => {
+      const unauthorizedAta = await getAssociatedTokenAddress(
+        mint,
+        unauthorizedUser.publicKey
+      );
+      try {
+        await program.methods
+          .revoke()
+          .accountsPartial({
+            ...revokeAccounts(revocablePda, revocableVault, unauthorizedUser.publicKey),
+            adminTokenAccount: unauthorizedAta,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+        expect.fail("Should have failed: unauthorized admin");
+      } catch (err: any) {
+        // Anchor's has_one constraint error
+        expect(err.toString()).to.include("Error");
+      }
+    });
+
+    it("T-51: revoke pays vested tokens and returns the rest to admin", async () => {
+      const adminBefore = Number((await getAccount(connection, adminAta)).amount);
+
+      await program.methods
+        .revoke()
+        .accountsPartial(revokeAccounts(revocablePda, revocableVault, admin.publicKey))
+        .signers([admin])
+        .rpc();
+
+      const vesting = await program.account.vestingAccount.fetch(revocablePda);
+      const adminAfter = Number((await getAccount(connection, adminAta)).amount);
+      const vaultAccount = await getAccount(connection, revocableVault);
+
+      expect(vesting.revokedAt.toNumber()).to.be.greaterThan(0);
+      expect(vesting.releasedAmount.toNumber()).to.be.greaterThan(0);
+      expect(Number(vaultAccount.amount)).to.equal(0);
+      expect(adminAfter - adminBefore).to.equal(
+        totalAmount - vesting.releasedAmount.toNumber()
+      );
+    });
+
+    it("T-52: revoking twice fails with AlreadyRevoked", async () => {
+      try {
+        await program.methods
+          .revoke()
+          .accountsPartial(revokeAccounts(revocablePda, revocableVault, admin.publicKey))
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have failed: already revoked");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("AlreadyRevoked");
+      }
+    });
+
+    it("T-53: irrevocable vesting cannot be revoked", async () => {
+      try {
+        await program.methods
+          .revoke()
+          .accountsPartial(
+            revokeAccounts(irrevocablePda, irrevocableVault, admin.publicKey)
+          )
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have failed: not revocable");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("NotRevocable");
+      }
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-7: Close & Rent Reclaim
+  // ─────────────────────────────────────────
+
+  describe("TR-7: Close & Rent Reclaim", () => {
+    const closeSeed = new BN(700);
+    let closePda: PublicKey;
+    let closeVault: PublicKey;
+    let expiredStart: number;
+    let expiredCliff: number;
+    let expiredEnd: number;
+
+    function closeAccounts() {
+      return {
+        admin: admin.publicKey,
+        mint,
+        vestingAccount: closePda,
+        vault: closeVault,
+        adminTokenAccount: adminAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      };
+    }
+
+    async function createExpired() {
+      await createGrant({
+        seed: closeSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(expiredStart),
+        cliffTime: new BN(expiredCliff),
+        endTime: new BN(expiredEnd),
+      });
+    }
+
+    before(async () => {
+      const now = await clusterNow();
+      expiredStart = now - 200;
+      expiredCliff = now - 150;
+      expiredEnd = now - 100;
+
+      closePda = derivePda(closeSeed);
+      closeVault = await getAssociatedTokenAddress(mint, closePda, true);
+
+      await createExpired();
+      await program.methods
+        .deposit()
+        .accountsPartial({
+          admin: admin.publicKey,
+          mint,
+          vestingAccount: closePda,
+          vault: closeVault,
+          adminTokenAccount: adminAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("T-60: close fails while tokens remain unreleased", async () => {
+      try {
+        await program.methods
+          .closeVesting()
+          .accountsPartial(closeAccounts())
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have failed: vesting not complete");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("VestingNotComplete");
+      }
+    });
+
+    it("T-61: close after full claim returns rent to admin", async () => {
+      await claimFrom(closePda, closeVault);
+
+      const vestingRent = (await connection.getAccountInfo(closePda))!.lamports;
+      const vaultRent = (await connection.getAccountInfo(closeVault))!.lamports;
+      const adminBefore = await connection.getBalance(admin.publicKey);
+
+      await program.methods
+        .closeVesting()
+        .accountsPartial(closeAccounts())
+        .signers([admin])
+        .rpc();
+
+      const adminAfter = await connection.getBalance(admin.publicKey);
+      expect(await connection.getAccountInfo(closePda)).to.be.null;
+      expect(await connection.getAccountInfo(closeVault)).to.be.null;
+      // Admin also pays the transaction fee
+      expect(adminAfter - adminBefore).to.be.greaterThan(
+        vestingRent + vaultRent - 10_000
+      );
+    });
+
+    it("T-62: seed can be reused after close", async () => {
+      await createExpired();
+      const vesting = await program.account.vestingAccount.fetch(closePda);
+      expect(vesting.releasedAmount.toNumber()).to.equal(0);
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-8: Funding State Machine
+  // ─────────────────────────────────────────
+
+  describe("TR-8: Funding State Machine", () => {
+    const dustSeed = new BN(800);
+    const dust = 1;
+    let dustPda: PublicKey;
+    let dustVault: PublicKey;
+
+    before(async () => {
+      const now = await clusterNow();
+
+      ({ pda: dustPda, pdaVault: dustVault } = await createGrant({
+        seed: dustSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(now - 200),
+        cliffTime: new BN(now - 150),
+        endTime: new BN(now - 100),
+      }));
+    });
+
+    it("T-70: new vesting starts in Created status", async () => {
+      const vesting = await program.account.vestingAccount.fetch(dustPda);
+      expect(vesting.status).to.deep.equal({ created: {} });
+    });
+
+    it("T-71: dust sent to the vault does not fund or block the vesting", async () => {
+      // Anyone can transfer tokens straight into the vault ATA
+      await transfer(connection, admin, adminAta, dustVault, admin, dust);
+
+      try {
+        await claimFrom(dustPda, dustVault);
+        expect.fail("Should have failed: not funded");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("NotFunded");
+      }
+
+      await program.methods
+        .deposit()
+        .accountsPartial({
+          admin: admin.publicKey,
+          mint,
+          vestingAccount: dustPda,
+          vault: dustVault,
+          adminTokenAccount: adminAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      const vesting = await program.account.vestingAccount.fetch(dustPda);
+      expect(vesting.status).to.deep.equal({ funded: {} });
+      const vaultAccount = await getAccount(connection, dustVault);
+      expect(Number(vaultAccount.amount)).to.equal(totalAmount + dust);
+    });
+
+    it("T-72: full claim completes the vesting and leaves only the surplus", async () => {
+      await claimFrom(dustPda, dustVault);
+
+      const vesting = await program.account.vestingAccount.fetch(dustPda);
+      expect(vesting.status).to.deep.equal({ completed: {} });
+      expect(vesting.releasedAmount.toNumber()).to.equal(totalAmount);
+      const vaultAccount = await getAccount(connection, dustVault);
+      expect(Number(vaultAccount.amount)).to.equal(dust);
+    });
+
+    it("T-73: close sweeps the surplus back to admin", async () => {
+      const adminBefore = Number((await getAccount(connection, adminAta)).amount);
+
+      await program.methods
+        .closeVesting()
+        .accountsPartial({
+          admin: admin.publicKey,
+          mint,
+          vestingAccount: dustPda,
+          vault: dustVault,
+          adminTokenAccount: adminAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      const adminAfter = Number((await getAccount(connection, adminAta)).amount);
+      expect(adminAfter - adminBefore).to.equal(dust);
+      expect(await connection.getAccountInfo(dustVault)).to.be.null;
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-9: Atomic Create & Fund
+  // ─────────────────────────────────────────
+
+  describe("TR-9: Atomic Create & Fund", () => {
+    const fundedSeed = new BN(900);
+    const fundedAmount = 1_000 * 10 ** decimals;
+    let fundedPda: PublicKey;
+    let fundedVault: PublicKey;
+    let now: number;
+
+    before(async () => {
+      now = await clusterNow();
+
+      fundedPda = derivePda(fundedSeed);
+      fundedVault = await getAssociatedTokenAddress(mint, fundedPda, true);
+    });
+
+    it("T-80: creates and funds the vesting in one instruction", async () => {
+      const adminBefore = Number((await getAccount(connection, adminAta)).amount);
+
+      await createAndFund({
+        seed: fundedSeed,
+        totalAmount: new BN(fundedAmount),
+        startTime: new BN(now - 10),
+        cliffTime: new BN(now + 20),
+        endTime: new BN(now + 60),
+      });
+
+      const vesting = await program.account.vestingAccount.fetch(fundedPda);
+      expect(vesting.status).to.deep.equal({ funded: {} });
+      expect(vesting.totalAmount.toNumber()).to.equal(fundedAmount);
+      const vaultAccount = await getAccount(connection, fundedVault);
+      expect(Number(vaultAccount.amount)).to.equal(fundedAmount);
+      const adminAfter = Number((await getAccount(connection, adminAta)).amount);
+      expect(adminBefore - adminAfter).to.equal(fundedAmount);
+    });
+
+    it("T-81: deposit on an atomically funded vesting fails", async () => {
+      try {
+        await program.methods
+          .deposit()
+          .accountsPartial({
+            admin: admin.publicKey,
+            mint,
+            vestingAccount: fundedPda,
+            vault: fundedVault,
+            adminTokenAccount: adminAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have failed: already funded");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("AlreadyFunded");
+      }
+    });
+
+    it("T-82: invalid schedule leaves no half-created vesting", async () => {
+      const badSeed = new BN(901);
+      const badPda = derivePda(badSeed);
+      const badVault = await getAssociatedTokenAddress(mint, badPda, true);
+
+      try {
+        await createAndFund({
+          seed: badSeed,
+          totalAmount: new BN(fundedAmount),
+          startTime: new BN(now + 60),
+          cliffTime: new BN(now + 20),
+          endTime: new BN(now - 10),
+        });
+        expect.fail("Should have failed: invalid time range");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("InvalidTimeRange");
+      }
+
+      expect(await connection.getAccountInfo(badPda)).to.be.null;
+      expect(await connection.getAccountInfo(badVault)).to.be.null;
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-10: Periodic Schedules
+  // ─────────────────────────────────────────
+
+  describe("TR-10: Periodic Schedules", () => {
+    const periodAmount = 1_000 * 10 ** decimals + 2; // not divisible by 3
+    const period = 100;
+    let now: number;
+
+    const createFunded = (
+      s: BN,
+      start: number,
+      end: number,
+      kind: any,
+      periodSeconds: number
+    ) =>
+      createAndFund({
+        seed: s,
+        totalAmount: new BN(periodAmount),
+        startTime: new BN(start),
+        cliffTime: new BN(start),
+        endTime: new BN(end),
+        scheduleKind: kind,
+        periodSeconds: new BN(periodSeconds),
+      });
+
+    before(async () => {
+      now = await clusterNow();
+    });
+
+    it("T-90: releases one equal step per completed period", async () => {
+      // 3 periods; the first boundary has passed, the second has not
+      const { pda, pdaVault } = await createFunded(
+        new BN(1000),
+        now - 120,
+        now - 120 + 3 * period,
+        { periodic: {} },
+        period
+      );
+
+      await claimFrom(pda, pdaVault);
+
+      const vesting = await program.account.vestingAccount.fetch(pda);
+      expect(vesting.releasedAmount.toNumber()).to.equal(
+        Math.floor(periodAmount / 3)
+      );
+    });
+
+    it("T-91: the final period releases the rounding remainder", async () => {
+      const { pda, pdaVault } = await createFunded(
+        new BN(1001),
+        now - 3 * period - 10,
+        now - 10,
+        { periodic: {} },
+        period
+      );
+
+      await claimFrom(pda, pdaVault);
+
+      const vesting = await program.account.vestingAccount.fetch(pda);
+      expect(vesting.releasedAmount.toNumber()).to.equal(periodAmount);
+      expect(vesting.status).to.deep.equal({ completed: {} });
+    });
+
+    it("T-92: rejects a periodic schedule without a valid period", async () => {
+      for (const [s, kind, periodSeconds] of [
+        [new BN(1002), { periodic: {} }, 0],
+        [new BN(1003), { periodic: {} }, 4 * period],
+        [new BN(1004), { linear: {} }, period],
+      ] as [BN, any, number][]) {
+        try {
+          await createFunded(s, now, now + 3 * period, kind, periodSeconds);
+          expect.fail("Should have failed: invalid period");
+        } catch (err: any) {
+          expect(err.error?.errorCode?.code).to.equal("InvalidPeriod");
+        }
+      }
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-11: Custom Tranche Schedules
+  // ─────────────────────────────────────────
+
+  describe("TR-11: Custom Tranche Schedules", () => {
+    const unit = 10 ** decimals;
+    const trancheAmount = 1_000 * unit;
+    let now: number;
+
+    const point = (timestamp: number, amount: number) => ({
+      timestamp: new BN(timestamp),
+      amount: new BN(amount),
+    });
+
+    const createFunded = (
+      s: BN,
+      start: number,
+      end: number,
+      kind: any,
+      points: { timestamp: BN; amount: BN }[]
+    ) =>
+      createAndFund({
+        seed: s,
+        totalAmount: new BN(trancheAmount),
+        startTime: new BN(start),
+        cliffTime: new BN(start),
+        endTime: new BN(end),
+        scheduleKind: kind,
+        schedulePoints: points,
+      });
+
+    before(async () => {
+      now = await clusterNow();
+    });
+
+    it("T-100: step table releases each reached tranche in full", async () => {
+      const end = now + 1000;
+      const { pda, pdaVault } = await createFunded(
+        new BN(1100),
+        now - 100,
+        end,
+        { customStep: {} },
+        [
+          point(now - 100, 100 * unit),
+          point(now - 10, 300 * unit),
+          point(end, trancheAmount),
+        ]
+      );
+
+      await claimFrom(pda, pdaVault);
+
+      const vesting = await program.account.vestingAccount.fetch(pda);
+      expect(vesting.scheduleLen).to.equal(3);
+      expect(vesting.releasedAmount.toNumber()).to.equal(300 * unit);
+    });
+
+    it("T-101: linear table interpolates between points after a jump", async () => {
+      // 10% at TGE, +20% at the jump, then linear to 100% at end
+      const jump = now - 50;
+      const end = now + 150;
+      const { pda, pdaVault } = await createFunded(
+        new BN(1101),
+        now - 100,
+        end,
+        { customLinear: {} },
+        [
+          point(now - 100, 100 * unit),
+          point(jump, 100 * unit),
+          point(jump, 300 * unit),
+          point(end, trancheAmount),
+        ]
+      );
+
+      await claimFrom(pda, pdaVault);
+
+      const vesting = await program.account.vestingAccount.fetch(pda);
+      const released = vesting.releasedAmount.toNumber();
+      expect(released).to.be.greaterThan(300 * unit);
+      expect(released).to.be.lessThan(trancheAmount);
+    });
+
+    it("T-102: rejects invalid schedule tables", async () => {
+      const end = now + 1000;
+      for (const [s, kind, points] of [
+        // amounts decrease
+        [new BN(1102), { customStep: {} }, [point(now, 500 * unit), point(now + 10, 400 * unit), point(end, trancheAmount)]],
+        // final point is not (end_time, total_amount)
+        [new BN(1103), { customLinear: {} }, [point(now, 100 * unit), point(end, 900 * unit)]],
+        // empty table
+        [new BN(1104), { customLinear: {} }, []],
+        // points on a non-custom schedule
+        [new BN(1105), { linear: {} }, [point(end, trancheAmount)]],
+      ] as [BN, any, { timestamp: BN; amount: BN }[]][]) {
+        try {
+          await createFunded(s, now, end, kind, points);
+          expect.fail("Should have failed: invalid schedule");
+        } catch (err: any) {
+          expect(err.error?.errorCode?.code).to.equal("InvalidSchedule");
+        }
+      }
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-12: Cliff Unlock Amount
+  // ─────────────────────────────────────────
+
+  describe("TR-12: Cliff Unlock Amount", () => {
+    const unit = 10 ** decimals;
+    const grantAmount = 1_000 * unit;
+    const cliffAmount = 250 * unit;
+    let now: number;
+
+    const createFunded = (
+      s: BN,
+      times: [number, number, number],
+      kind: any,
+      periodSeconds: number,
+      cliff: number,
+      accrueFromCliff: boolean
+    ) =>
+      createAndFund({
+        seed: s,
+        totalAmount: new BN(grantAmount),
+        startTime: new BN(times[0]),
+        cliffTime: new BN(times[1]),
+        endTime: new BN(times[2]),
+        scheduleKind: kind,
+        periodSeconds: new BN(periodSeconds),
+        cliffAmount: new BN(cliff),
+        accrueFromCliff,
+      });
+
+    before(async () => {
+      now = await clusterNow();
+    });
+
+    it("T-110: cliff_amount unlocks at the cliff, accrual starts there", async () => {
+      // One period from cliff to end: only the cliff unlock is out before end
+      const cliff = now - 100;
+      const end = now + 1000;
+      const { pda, pdaVault } = await createFunded(
+        new BN(1200),
+        [now - 500, cliff, end],
+        { periodic: {} },
+        end - cliff,
+        cliffAmount,
+        true
+      );
+
+      await claimFrom(pda, pdaVault);
+
+      const vesting = await program.account.vestingAccount.fetch(pda);
+      expect(vesting.cliffAmount.toNumber()).to.equal(cliffAmount);
+      expect(vesting.accrueFromCliff).to.equal(true);
+      expect(vesting.releasedAmount.toNumber()).to.equal(cliffAmount);
+    });
+
+    it("T-111: linear accrual from start adds the pro-rata part to the cliff unlock", async () => {
+      const { pda, pdaVault } = await createFunded(
+        new BN(1201),
+        [now - 500, now - 100, now + 1000],
+        { linear: {} },
+        0,
+        cliffAmount,
+        false
+      );
+
+      await claimFrom(pda, pdaVault);
+
+      const vesting = await program.account.vestingAccount.fetch(pda);
+      const released = vesting.releasedAmount.toNumber();
+      // cliff unlock + at least 500/1500 of the remaining 750
+      expect(released).to.be.at.least(cliffAmount + 250 * unit);
+      expect(released).to.be.lessThan(grantAmount);
+    });
+
+    it("T-112: nothing unlocks before the cliff", async () => {
+      const { pda, pdaVault } = await createFunded(
+        new BN(1202),
+        [now - 10, now + 500, now + 1000],
+        { linear: {} },
+        0,
+        cliffAmount,
+        true
+      );
+
+      try {
+        await claimFrom(pda, pdaVault);
+        expect.fail("Should have failed: cliff not reached");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("NothingToClaim");
+      }
+    });
+
+    it("T-113: rejects cliff_amount above total_amount", async () => {
+      try {
+        await createFunded(
+          new BN(1203),
+          [now, now + 10, now + 100],
+          { linear: {} },
+          0,
+          grantAmount + 1,
+          false
+        );
+        expect.fail("Should have failed: cliff_amount > total_amount");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("InvalidCliffAmount");
+      }
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-13: Partial Claims
+  // ─────────────────────────────────────────
+
+  describe("TR-13: Partial Claims", () => {
+    const partialSeed = new BN(1300);
+    const partialAmount = 1_000 * 10 ** decimals;
+    const firstClaim = 123 * 10 ** decimals;
+    let partialPda: PublicKey;
+    let partialVault: PublicKey;
+
+    before(async () => {
+      const now = await clusterNow();
+
+      // Fully vested, so the whole grant is claimable
+      ({ pda: partialPda, pdaVault: partialVault } = await createAndFund({
+        seed: partialSeed,
+        totalAmount: new BN(partialAmount),
+        startTime: new BN(now - 200),
+        cliffTime: new BN(now - 150),
+        endTime: new BN(now - 100),
+      }));
+    });
+
+    it("T-130: claims only the requested amount", async () => {
+      await claimFrom(partialPda, partialVault, new BN(firstClaim));
+
+      const vesting = await program.account.vestingAccount.fetch(partialPda);
+      expect(vesting.releasedAmount.toNumber()).to.equal(firstClaim);
+      expect(vesting.status).to.deep.equal({ funded: {} });
+    });
+
+    it("T-131: rejects an amount above the claimable balance", async () => {
+      try {
+        await claimFrom(partialPda, partialVault, new BN(partialAmount - firstClaim + 1));
+        expect.fail("Should have failed: amount exceeds claimable");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal(
+          "ClaimAmountExceedsClaimable"
+        );
+      }
+    });
+
+    it("T-132: claiming without an amount takes the remainder", async () => {
+      await claimFrom(partialPda, partialVault);
+
+      const vesting = await program.account.vestingAccount.fetch(partialPda);
+      expect(vesting.releasedAmount.toNumber()).to.equal(partialAmount);
+      expect(vesting.status).to.deep.equal({ completed: {} });
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-14: Claim To Destination
+  // ─────────────────────────────────────────
+
+  describe("TR-14: Claim To Destination", () => {
+    const claimToSeed = new BN(1400);
+    const claimToAmount = 1_000 * 10 ** decimals;
+    const treasury = Keypair.generate();
+    let claimToPda: PublicKey;
+    let claimToVault: PublicKey;
+    let treasuryAccount: PublicKey;
+
+    const claimTo = (destination: PublicKey, signer: Keypair) =>
+      program.methods
+        .claimTo(null)
+        .accountsPartial({
+          beneficiary: signer.publicKey,
+          mint,
+          vestingAccount: claimToPda,
+          vault: claimToVault,
+          destination,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      const now = await clusterNow();
+
+      ({ pda: claimToPda, pdaVault: claimToVault } = await createAndFund({
+        seed: claimToSeed,
+        totalAmount: new BN(claimToAmount),
+        startTime: new BN(now - 200),
+        cliffTime: new BN(now - 150),
+        endTime: new BN(now - 100),
+      }));
+
+      // Non-ATA token account owned by someone other than the beneficiary
+      treasuryAccount = await createAccount(
+        connection,
+        admin,
+        mint,
+        treasury.publicKey,
+        Keypair.generate()
+      );
+    });
+
+    it("T-140: rejects a destination for a different mint", async () => {
+      const otherMint = await createMint(
+        connection,
+        admin,
+        admin.publicKey,
+        null,
+        decimals
+      );
+      const otherAccount = await createAccount(
+        connection,
+        admin,
+        otherMint,
+        treasury.publicKey,
+        Keypair.generate()
+      );
+
+      try {
+        await claimTo(otherAccount, beneficiary);
+        expect.fail("Should have failed: destination mint mismatch");
+      } catch (err: any) {
+        expect(err.toString()).to.include("Error");
+      }
+    });
+
+    it("T-141: only the beneficiary can claim to a destination", async () => {
+      try {
+        await claimTo(treasuryAccount, unauthorizedUser);
+        expect.fail("Should have failed: not the beneficiary");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("UnauthorizedBeneficiary");
+      }
+    });
+
+    it("T-142: sends claimed tokens to any token account of the mint", async () => {
+      await claimTo(treasuryAccount, beneficiary);
+
+      const treasuryBalance = await getAccount(connection, treasuryAccount);
+      expect(Number(treasuryBalance.amount)).to.equal(claimToAmount);
+      const vesting = await program.account.vestingAccount.fetch(claimToPda);
+      expect(vesting.releasedAmount.toNumber()).to.equal(claimToAmount);
+      expect(vesting.status).to.deep.equal({ completed: {} });
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-15: Permissionless Crank Claims
+  // ─────────────────────────────────────────
+
+  describe("TR-15: Permissionless Crank Claims", () => {
+    const crankSeed = new BN(1500);
+    const lockedSeed = new BN(1501);
+    const crankAmount = 1_000 * 10 ** decimals;
+    const cranker = Keypair.generate();
+    let crankPda: PublicKey;
+    let crankVault: PublicKey;
+    let lockedPda: PublicKey;
+    let lockedVault: PublicKey;
+
+    const crank = (
+      vestingAccount: PublicKey,
+      vault: PublicKey,
+      beneficiaryTokenAccount?: PublicKey
+    ) =>
+      program.methods
+        .crankClaim()
+        .accountsPartial({
+          cranker: cranker.publicKey,
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount,
+          vault,
+          ...(beneficiaryTokenAccount ? { beneficiaryTokenAccount } : {}),
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([cranker])
+        .rpc();
+
+    before(async () => {
+      await airdropSol(connection, cranker.publicKey, LAMPORTS_PER_SOL);
+
+      const now = await clusterNow();
+      const createExpired = (expiredSeed: BN, allowPermissionlessClaim: boolean) =>
+        createAndFund({
+          seed: expiredSeed,
+          totalAmount: new BN(crankAmount),
+          startTime: new BN(now - 200),
+          cliffTime: new BN(now - 150),
+          endTime: new BN(now - 100),
+          allowPermissionlessClaim,
+        });
+
+      ({ pda: crankPda, pdaVault: crankVault } = await createExpired(crankSeed, true));
+      ({ pda: lockedPda, pdaVault: lockedVault } = await createExpired(lockedSeed, false));
+    });
+
+    it("T-150: crank fails unless the vesting opted in", async () => {
+      try {
+        await crank(lockedPda, lockedVault);
+        expect.fail("Should have failed: permissionless claims disabled");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("PermissionlessClaimDisabled");
+      }
+    });
+
+    it("T-151: crank only pays into the beneficiary's canonical ATA", async () => {
+      const otherAccount = await createAccount(
+        connection,
+        admin,
+        mint,
+        beneficiary.publicKey,
+        Keypair.generate()
+      );
+
+      try {
+        await crank(crankPda, crankVault, otherAccount);
+        expect.fail("Should have failed: destination is not the beneficiary ATA");
+      } catch (err: any) {
+        expect(err.toString()).to.include("Error");
+      }
+    });
+
+    it("T-152: any caller can push claimable tokens to the beneficiary", async () => {
+      const beneficiaryAta = await getAssociatedTokenAddress(
+        mint,
+        beneficiary.publicKey
+      );
+      const before = await getAccount(connection, beneficiaryAta);
+
+      await crank(crankPda, crankVault);
+
+      const after = await getAccount(connection, beneficiaryAta);
+      expect(Number(after.amount) - Number(before.amount)).to.equal(crankAmount);
+      const vesting = await program.account.vestingAccount.fetch(crankPda);
+      expect(vesting.releasedAmount.toNumber()).to.equal(crankAmount);
+      expect(vesting.status).to.deep.equal({ completed: {} });
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-16: Claim Delegate
+  // ─────────────────────────────────────────
+
+  describe("TR-16: Claim Delegate", () => {
+    const delegateSeed = new BN(1600);
+    const delegateAmount = 1_000 * 10 ** decimals;
+    const delegate = Keypair.generate();
+    let delegatePda: PublicKey;
+    let delegateVault: PublicKey;
+    let beneficiaryAta: PublicKey;
+
+    const claimAs = (claimer: Keypair, beneficiaryTokenAccount: PublicKey) =>
+      program.methods
+        .claim(null)
+        .accountsPartial({
+          claimer: claimer.publicKey,
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount: delegatePda,
+          vault: delegateVault,
+          beneficiaryTokenAccount,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([claimer])
+        .rpc();
+
+    const setDelegate = (signer: Keypair, claimDelegate: PublicKey) =>
+      program.methods
+        .setClaimDelegate(claimDelegate)
+        .accountsPartial({
+          beneficiary: signer.publicKey,
+          vestingAccount: delegatePda,
+        })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      await airdropSol(connection, delegate.publicKey, LAMPORTS_PER_SOL);
+
+      const now = await clusterNow();
+
+      delegatePda = derivePda(delegateSeed);
+      delegateVault = await getAssociatedTokenAddress(mint, delegatePda, true);
+      beneficiaryAta = await getAssociatedTokenAddress(mint, beneficiary.publicKey);
+
+      await createAndFund({
+        seed: delegateSeed,
+        totalAmount: new BN(delegateAmount),
+        startTime: new BN(now - 200),
+        cliffTime: new BN(now - 150),
+        endTime: new BN(now - 100),
+      });
+    });
+
+    it("T-160: only the beneficiary can set the claim delegate", async () => {
+      try {
+        await setDelegate(unauthorizedUser, unauthorizedUser.publicKey);
+        expect.fail("Should have failed: not the beneficiary");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("UnauthorizedBeneficiary");
+      }
+    });
+
+    it("T-161: an unregistered key cannot claim", async () => {
+      try {
+        await claimAs(delegate, beneficiaryAta);
+        expect.fail("Should have failed: delegate not registered");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("UnauthorizedBeneficiary");
+      }
+    });
+
+    it("T-162: the delegate cannot redirect tokens to itself", async () => {
+      await setDelegate(beneficiary, delegate.publicKey);
+      const vesting = await program.account.vestingAccount.fetch(delegatePda);
+      expect(vesting.claimDelegate.toBase58()).to.equal(delegate.publicKey.toBase58());
+
+      const delegateAta = (
+        await getOrCreateAssociatedTokenAccount(
+          connection,
+          admin,
+          mint,
+          delegate.publicKey
+        )
+      ).address;
+
+      try {
+        await claimAs(delegate, delegateAta);
+        expect.fail("Should have failed: destination is not the beneficiary ATA");
+      } catch (err: any) {
+        expect(err.toString()).to.include("Error");
+      }
+    });
+
+    it("T-163: the delegate claims into the beneficiary's ATA", async () => {
+      const before = await getAccount(connection, beneficiaryAta);
+
+      await claimAs(delegate, beneficiaryAta);
+
+      const after = await getAccount(connection, beneficiaryAta);
+      expect(Number(after.amount) - Number(before.amount)).to.equal(delegateAmount);
+      const vesting = await program.account.vestingAccount.fetch(delegatePda);
+      expect(vesting.status).to.deep.equal({ completed: {} });
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-17: Admin Transfer
+  // ─────────────────────────────────────────
+
+  describe("TR-17: Admin Transfer", () => {
+    const transferSeed = new BN(1700);
+    const transferAmount = 1_000 * 10 ** decimals;
+    const newAdmin = Keypair.generate();
+    let transferPda: PublicKey;
+    let transferVault: PublicKey;
+
+    const proposeAdmin = (signer: Keypair, proposed: PublicKey) =>
+      program.methods
+        .proposeAdmin(proposed)
+        .accountsPartial({
+          admin: signer.publicKey,
+          vestingAccount: transferPda,
+        })
+        .signers([signer])
+        .rpc();
+
+    const acceptAdmin = (signer: Keypair) =>
+      program.methods
+        .acceptAdmin()
+        .accountsPartial({
+          newAdmin: signer.publicKey,
+          vestingAccount: transferPda,
+        })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      await airdropSol(connection, newAdmin.publicKey, LAMPORTS_PER_SOL);
+
+      const now = await clusterNow();
+
+      ({ pda: transferPda, pdaVault: transferVault } = await createAndFund({
+        seed: transferSeed,
+        totalAmount: new BN(transferAmount),
+        startTime: new BN(now - 100),
+        cliffTime: new BN(now - 50),
+        endTime: new BN(now + 1000),
+        revocable: true,
+      }));
+    });
+
+    it("T-170: only the admin can propose a new admin", async () => {
+      try {
+        await proposeAdmin(unauthorizedUser, unauthorizedUser.publicKey);
+        expect.fail("Should have failed: not the admin");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("UnauthorizedAdmin");
+      }
+    });
+
+    it("T-171: only the pending admin can accept", async () => {
+      await proposeAdmin(admin, newAdmin.publicKey);
+      const vesting = await program.account.vestingAccount.fetch(transferPda);
+      expect(vesting.pendingAdmin.toBase58()).to.equal(newAdmin.publicKey.toBase58());
+
+      try {
+        await acceptAdmin(unauthorizedUser);
+        expect.fail("Should have failed: not the pending admin");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("NotPendingAdmin");
+      }
+    });
+
+    it("T-172: accepting hands over admin authority", async () => {
+      await acceptAdmin(newAdmin);
+
+      const vesting = await program.account.vestingAccount.fetch(transferPda);
+      expect(vesting.admin.toBase58()).to.equal(newAdmin.publicKey.toBase58());
+      expect(vesting.pendingAdmin.toBase58()).to.equal(PublicKey.default.toBase58());
+      expect(vesting.seedAdmin.toBase58()).to.equal(admin.publicKey.toBase58());
+
+      try {
+        await proposeAdmin(admin, admin.publicKey);
+        expect.fail("Should have failed: old admin lost authority");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("UnauthorizedAdmin");
+      }
+    });
+
+    it("T-173: the new admin can revoke; the vault still signs", async () => {
+      const newAdminAta = (
+        await getOrCreateAssociatedTokenAccount(
+          connection,
+          newAdmin,
+          mint,
+          newAdmin.publicKey
+        )
+      ).address;
+      const beneficiaryAta = await getAssociatedTokenAddress(
+        mint,
+        beneficiary.publicKey
+      );
+
+      await program.methods
+        .revoke()
+        .accountsPartial({
+          admin: newAdmin.publicKey,
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount: transferPda,
+          vault: transferVault,
+          beneficiaryTokenAccount: beneficiaryAta,
+          adminTokenAccount: newAdminAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([newAdmin])
+        .rpc();
+
+      const vesting = await program.account.vestingAccount.fetch(transferPda);
+      expect(vesting.status).to.deep.equal({ revoked: {} });
+      const returned = await getAccount(connection, newAdminAta);
+      expect(Number(returned.amount)).to.be.greaterThan(0);
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-18: Beneficiary Transfer
+  // ─────────────────────────────────────────
+
+  describe("TR-18: Beneficiary Transfer", () => {
+    const transferableSeed = new BN(1800);
+    const lockedSeed = new BN(1801);
+    const grantAmount = 1_000 * 10 ** decimals;
+    const newBeneficiary = Keypair.generate();
+    let transferablePda: PublicKey;
+    let transferableVault: PublicKey;
+    let lockedPda: PublicKey;
+
+    const transfer = (
+      vestingAccount: PublicKey,
+      to: PublicKey,
+      cosigner: Keypair | null
+    ) =>
+      program.methods
+        .transferBeneficiary(to)
+        .accountsPartial({
+          beneficiary: beneficiary.publicKey,
+          admin: cosigner ? cosigner.publicKey : null,
+          vestingAccount,
+        })
+        .signers(cosigner ? [beneficiary, cosigner] : [beneficiary])
+        .rpc();
+
+    before(async () => {
+      await airdropSol(connection, newBeneficiary.publicKey, LAMPORTS_PER_SOL);
+
+      const now = await clusterNow();
+      const createExpired = (grantSeed: BN, transferable: boolean) =>
+        createAndFund({
+          seed: grantSeed,
+          totalAmount: new BN(grantAmount),
+          startTime: new BN(now - 200),
+          cliffTime: new BN(now - 150),
+          endTime: new BN(now - 100),
+          transferable,
+        });
+
+      ({ pda: transferablePda, pdaVault: transferableVault } = await createExpired(
+        transferableSeed,
+        true
+      ));
+      ({ pda: lockedPda } = await createExpired(lockedSeed, false));
+    });
+
+    it("T-180: non-transferable grants need the admin's co-signature", async () => {
+      try {
+        await transfer(lockedPda, newBeneficiary.publicKey, null);
+        expect.fail("Should have failed: not transferable");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("NotTransferable");
+      }
+    });
+
+    it("T-181: admin co-signature allows the transfer", async () => {
+      await transfer(lockedPda, newBeneficiary.publicKey, admin);
+
+      const vesting = await program.account.vestingAccount.fetch(lockedPda);
+      expect(vesting.beneficiary.toBase58()).to.equal(newBeneficiary.publicKey.toBase58());
+    });
+
+    it("T-182: the new beneficiary claims from the unchanged vesting address", async () => {
+      await transfer(transferablePda, newBeneficiary.publicKey, null);
+
+      const vesting = await program.account.vestingAccount.fetch(transferablePda);
+      expect(vesting.beneficiary.toBase58()).to.equal(newBeneficiary.publicKey.toBase58());
+      expect(vesting.seedBeneficiary.toBase58()).to.equal(beneficiary.publicKey.toBase58());
+
+      const newBeneficiaryAta = await getAssociatedTokenAddress(
+        mint,
+        newBeneficiary.publicKey
+      );
+      const claimAs = async (signer: Keypair) =>
+        program.methods
+          .claim(null)
+          .accountsPartial({
+            claimer: signer.publicKey,
+            beneficiary: signer.publicKey,
+            mint,
+            vestingAccount: transferablePda,
+            vault: transferableVault,
+            beneficiaryTokenAccount: await getAssociatedTokenAddress(
+              mint,
+              signer.publicKey
+            ),
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([signer])
+          .rpc();
+
+      try {
+        await claimAs(beneficiary);
+        expect.fail("Should have failed: previous beneficiary");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("UnauthorizedBeneficiary");
+      }
+
+      await claimAs(newBeneficiary);
+      const balance = await getAccount(connection, newBeneficiaryAta);
+      expect(Number(balance.amount)).to.equal(grantAmount);
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-19: Program Config & Pause
+  // ─────────────────────────────────────────
+
+  describe("TR-19: Program Config & Pause", () => {
+    const pauseSeed = new BN(1900);
+    const pauseAmount = 1_000 * 10 ** decimals;
+    const PAUSE_CLAIM = 1 << 2;
+    let configPda: PublicKey;
+    let programData: PublicKey;
+    let pausePda: PublicKey;
+    let pauseVault: PublicKey;
+
+    const updateConfig = (paused: boolean, pauseFlags: number, authority?: Keypair) =>
+      program.methods
+        .updateConfig(paused, pauseFlags, provider.wallet.publicKey, new BN(0), 0, [])
+        .accountsPartial({
+          authority: authority ? authority.publicKey : provider.wallet.publicKey,
+          config: configPda,
+          program: program.programId,
+          programData,
+        })
+        .signers(authority ? [authority] : [])
+        .rpc();
+
+    const claim = () => claimFrom(pausePda, pauseVault);
+
+    before(async () => {
+      [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        program.programId
+      );
+      [programData] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        BPF_LOADER_UPGRADEABLE_ID
+      );
+
+      const now = await clusterNow();
+
+      ({ pda: pausePda, pdaVault: pauseVault } = await createAndFund({
+        seed: pauseSeed,
+        totalAmount: new BN(pauseAmount),
+        startTime: new BN(now - 200),
+        cliffTime: new BN(now - 150),
+        endTime: new BN(now - 100),
+      }));
+    });
+
+    after(async () => {
+      // Leave the shared config unpaused for the other suites
+      await updateConfig(false, 0);
+    });
+
+    it("T-190: only the upgrade authority can update config", async () => {
+      try {
+        await updateConfig(true, 0, unauthorizedUser);
+        expect.fail("Should have failed: not the upgrade authority");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("UnauthorizedConfigAuthority");
+      }
+    });
+
+    it("T-191: the claim pause bit blocks claims", async () => {
+      await updateConfig(false, PAUSE_CLAIM);
+      const config = await program.account.config.fetch(configPda);
+      expect(config.pauseFlags).to.equal(PAUSE_CLAIM);
+
+      try {
+        await claim();
+        expect.fail("Should have failed: claims paused");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("ProgramPaused");
+      }
+    });
+
+    it("T-192: the global pause blocks every instruction", async () => {
+      await updateConfig(true, 0);
+
+      try {
+        await program.methods
+          .setClaimDelegate(PublicKey.default)
+          .accountsPartial({
+            beneficiary: beneficiary.publicKey,
+            vestingAccount: pausePda,
+          })
+          .signers([beneficiary])
+          .rpc();
+        expect.fail("Should have failed: program paused");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("ProgramPaused");
+      }
+    });
+
+    it("T-193: unpausing restores claims", async () => {
+      await updateConfig(false, 0);
+      await claim();
+
+      const vesting = await program.account.vestingAccount.fetch(pausePda);
+      expect(vesting.status).to.deep.equal({ completed: {} });
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-20: Protocol Fees
+  // ─────────────────────────────────────────
+
+  describe("TR-20: Protocol Fees", () => {
+    const feeAmount = 1_000 * 10 ** decimals;
+    const creationFee = 0.01 * LAMPORTS_PER_SOL;
+    const feeBps = 250; // 2.5%
+    const treasury = Keypair.generate();
+    let configPda: PublicKey;
+    let programData: PublicKey;
+    let treasuryAta: PublicKey;
+
+    const updateFees = (lamports: number, bps: number, exempt: PublicKey[] = []) =>
+      program.methods
+        .updateConfig(false, 0, treasury.publicKey, new BN(lamports), bps, exempt)
+        .accountsPartial({
+          authority: provider.wallet.publicKey,
+          config: configPda,
+          program: program.programId,
+          programData,
+        })
+        .rpc();
+
+    const createWithFees = async (feeSeed: BN, treasuryTokenAccount: PublicKey | null) => {
+      const now = await clusterNow();
+      return createAndFund(
+        {
+          seed: feeSeed,
+          totalAmount: new BN(feeAmount),
+          startTime: new BN(now),
+          cliffTime: new BN(now + 100),
+          endTime: new BN(now + 1000),
+        },
+        { treasury: treasury.publicKey, treasuryTokenAccount }
+      );
+    };
+
+    before(async () => {
+      [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        program.programId
+      );
+      [programData] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        BPF_LOADER_UPGRADEABLE_ID
+      );
+      treasuryAta = (
+        await getOrCreateAssociatedTokenAccount(connection, admin, mint, treasury.publicKey)
+      ).address;
+    });
+
+    after(async () => {
+      // Leave the shared config fee-free for the other suites
+      await program.methods
+        .updateConfig(false, 0, provider.wallet.publicKey, new BN(0), 0, [])
+        .accountsPartial({
+          authority: provider.wallet.publicKey,
+          config: configPda,
+          program: program.programId,
+          programData,
+        })
+        .rpc();
+    });
+
+    it("T-200: rejects a deposit fee above 100%", async () => {
+      try {
+        await updateFees(0, 10_001);
+        expect.fail("Should have failed: fee bps above 10000");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("InvalidFeeConfig");
+      }
+    });
+
+    it("T-201: charges the SOL and token fees on top of total_amount", async () => {
+      await updateFees(creationFee, feeBps);
+
+      const treasuryLamportsBefore = await connection.getBalance(treasury.publicKey);
+      const treasuryTokensBefore = Number((await getAccount(connection, treasuryAta)).amount);
+      const adminTokensBefore = Number((await getAccount(connection, adminAta)).amount);
+
+      const { pdaVault } = await createWithFees(new BN(2000), treasuryAta);
+
+      const expectedFee = (feeAmount * feeBps) / 10_000;
+      expect(await connection.getBalance(treasury.publicKey)).to.equal(
+        treasuryLamportsBefore + creationFee
+      );
+      expect(Number((await getAccount(connection, treasuryAta)).amount)).to.equal(
+        treasuryTokensBefore + expectedFee
+      );
+      expect(Number((await getAccount(connection, adminAta)).amount)).to.equal(
+        adminTokensBefore - feeAmount - expectedFee
+      );
+      // The vault still holds exactly total_amount
+      expect(Number((await getAccount(connection, pdaVault)).amount)).to.equal(feeAmount);
+    });
+
+    it("T-202: a token fee without a treasury token account fails", async () => {
+      try {
+        await createWithFees(new BN(2001), null);
+        expect.fail("Should have failed: treasury token account missing");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("MissingTreasuryAccount");
+      }
+    });
+
+    it("T-203: fee-exempt admins pay no fees", async () => {
+      await updateFees(creationFee, feeBps, [admin.publicKey]);
+
+      const treasuryLamportsBefore = await connection.getBalance(treasury.publicKey);
+      const treasuryTokensBefore = Number((await getAccount(connection, treasuryAta)).amount);
+
+      await createWithFees(new BN(2002), treasuryAta);
+
+      expect(await connection.getBalance(treasury.publicKey)).to.equal(treasuryLamportsBefore);
+      expect(Number((await getAccount(connection, treasuryAta)).amount)).to.equal(
+        treasuryTokensBefore
+      );
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-21: Batch Creation
+  // ─────────────────────────────────────────
+
+  describe("TR-21: Batch Creation", () => {
+    const batchAmount = 1_000 * 10 ** decimals;
+    const batchBeneficiaries = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+    const buildBatch = async (seeds: BN[]) => {
+      const now = await clusterNow();
+
+      return Promise.all(
+        seeds.map(async (batchSeed, i) => {
+          const owner = batchBeneficiaries[i].publicKey;
+          const pda = derivePda(batchSeed, owner);
+          return {
+            pda,
+            vault: await getAssociatedTokenAddress(mint, pda, true),
+            entry: {
+              beneficiary: owner,
+              params: withDefaults({
+                seed: batchSeed,
+                totalAmount: new BN(batchAmount * (i + 1)),
+                startTime: new BN(now),
+                cliffTime: new BN(now + 100),
+                endTime: new BN(now + 1000),
+              }),
+            },
+          };
+        })
+      );
+    };
+
+    const createBatch = (batch: Awaited<ReturnType<typeof buildBatch>>) =>
+      program.methods
+        .createVestingBatch(batch.map((b) => b.entry))
+        .accountsPartial({
+          admin: admin.publicKey,
           mint,
-          vestingAccount: minPda,
-          vault: minVault,
+          adminTokenAccount: adminAta,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         })
+        .remainingAccounts(
+          batch.flatMap((b) => [
+            { pubkey: b.pda, isSigner: false, isWritable: true },
+            { pubkey: b.vault, isSigner: false, isWritable: true },
+          ])
+        )
         .signers([admin])
         .rpc();
 
-      const vesting = await program.account.vestingAccount.fetch(minPda);
-      expect(vesting.totalAmount.toNumber()).to.equal(1);
+    it("T-210: creates and funds one vesting per entry", async () => {
+      const batch = await buildBatch([new BN(2100), new BN(2101), new BN(2102)]);
+      await createBatch(batch);
+
+      for (let i = 0; i < batch.length; i++) {
+        const b = batch[i];
+        const vesting = await program.account.vestingAccount.fetch(b.pda);
+        expect(vesting.beneficiary.toString()).to.equal(
+          batchBeneficiaries[i].publicKey.toString()
+        );
+        expect(vesting.status).to.deep.equal({ funded: {} });
+        const vaultAccount = await getAccount(connection, b.vault);
+        expect(Number(vaultAccount.amount)).to.equal(batchAmount * (i + 1));
+      }
     });
 
-    it("T-35: large total_amount does not overflow (u128 safe math)", async () => {
-      const bigSeed = new BN(205);
-      const [bigPda] = PublicKey.findProgramAddressSync(
+    it("T-211: remaining accounts out of entry order are rejected", async () => {
+      const batch = await buildBatch([new BN(2110), new BN(2111)]);
+      [batch[0].pda, batch[1].pda] = [batch[1].pda, batch[0].pda];
+
+      try {
+        await createBatch(batch);
+        expect.fail("Should have failed: accounts do not match entries");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("InvalidBatchAccounts");
+      }
+    });
+
+    it("T-212: an empty batch is rejected", async () => {
+      try {
+        await createBatch([]);
+        expect.fail("Should have failed: empty batch");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("InvalidBatchAccounts");
+      }
+    });
+
+    it("T-213: a pre-funded PDA or pre-created vault does not block the batch", async () => {
+      const batch = await buildBatch([new BN(2130), new BN(2131)]);
+
+      // A third party funds the first PDA and creates the second vault
+      const griefer = Keypair.generate();
+      await airdropSol(connection, griefer.publicKey, LAMPORTS_PER_SOL);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: griefer.publicKey,
+            toPubkey: batch[0].pda,
+            lamports: 1_000_000,
+          }),
+          createAssociatedTokenAccountIdempotentInstruction(
+            griefer.publicKey,
+            batch[1].vault,
+            batch[1].pda,
+            mint
+          )
+        ),
+        [griefer]
+      );
+
+      await createBatch(batch);
+
+      for (const b of batch) {
+        const vesting = await program.account.vestingAccount.fetch(b.pda);
+        expect(vesting.status).to.deep.equal({ funded: {} });
+      }
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-22: Merkle Distributor
+  // ─────────────────────────────────────────
+
+  describe("TR-22: Merkle Distributor", () => {
+    const merkleSeed = new BN(2200);
+    const leafAmount = 1_000 * 10 ** decimals;
+    const recipients = [beneficiary, Keypair.generate(), Keypair.generate()];
+    let merklePda: PublicKey;
+    let merkleVault: PublicKey;
+    let leaves: any[];
+    let tree: { root: Buffer; proofs: Buffer[][] };
+
+    // Claim status is per leaf: ["claim_status", merkle_vesting, beneficiary, leaf_hash]
+    const claimStatusPda = (index: number, signer: Keypair, distributor = merklePda) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("claim_status"),
+          distributor.toBuffer(),
+          signer.publicKey.toBuffer(),
+          hashMerkleLeaf(leaves[index]),
+        ],
+        program.programId
+      )[0];
+
+    /** `create_merkle_vesting` from `admin`; returns the distributor PDA and its vault */
+    const createDistributor = async (
+      seed: BN,
+      root: Buffer,
+      totalAmount: number,
+      clawbackTime = 0
+    ) => {
+      const [pda] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
+          Buffer.from("merkle_vesting"),
+          admin.publicKey.toBuffer(),
           mint.toBuffer(),
-          bigSeed.toArrayLike(Buffer, "le", 8),
+          seed.toArrayLike(Buffer, "le", 8),
         ],
         program.programId
       );
-      const bigVault = await getAssociatedTokenAddress(mint, bigPda, true);
-
-      // Use a large amount near u64 max (but representable in BN)
-      // u64::MAX = 18446744073709551615, use a large but valid value
-      const largeAmount = new BN("1000000000000000000"); // 10^18
+      const vault = await getAssociatedTokenAddress(mint, pda, true);
 
       await program.methods
-        .createVesting(
-          bigSeed,
-          largeAmount,
-          new BN(startTime),
-          new BN(cliffTime),
-          new BN(endTime)
-        )
+        .createMerkleVesting(seed, Array.from(root), new BN(totalAmount), new BN(clawbackTime))
         .accountsPartial({
           admin: admin.publicKey,
-          beneficiary: beneficiary.publicKey,
           mint,
-          vestingAccount: bigPda,
-          vault: bigVault,
+          merkleVesting: pda,
+          vault,
+          adminTokenAccount: adminAta,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         })
         .signers([admin])
         .rpc();
+      return { pda, vault };
+    };
 
-      const vesting = await program.account.vestingAccount.fetch(bigPda);
-      expect(vesting.totalAmount.toString()).to.equal(
-        largeAmount.toString()
+    const closeDistributor = (distributor: PublicKey, vault: PublicKey) =>
+      program.methods
+        .closeMerkleVesting()
+        .accountsPartial({
+          admin: admin.publicKey,
+          mint,
+          merkleVesting: distributor,
+          vault,
+          adminTokenAccount: adminAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+    const closeClaimStatus = (index: number, signer: Keypair, distributor: PublicKey) =>
+      program.methods
+        .closeClaimStatus()
+        .accountsPartial({
+          beneficiary: signer.publicKey,
+          merkleVesting: distributor,
+          claimStatus: claimStatusPda(index, signer, distributor),
+        })
+        .signers([signer])
+        .rpc();
+
+    const claimMerkle = (index: number, proof: Buffer[], signer = recipients[index]) =>
+      program.methods
+        .claimMerkle(leaves[index], proof.map((p) => Array.from(p)), null)
+        .accountsPartial({
+          beneficiary: signer.publicKey,
+          mint,
+          merkleVesting: merklePda,
+          vault: merkleVault,
+          claimStatus: claimStatusPda(index, signer),
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      await Promise.all(
+        recipients.slice(1).map((r) => airdropSol(connection, r.publicKey, LAMPORTS_PER_SOL))
       );
+
+      const now = await clusterNow();
+
+      // Fully vested for the first two recipients, still before the cliff for the third
+      leaves = recipients.map((r, i) => ({
+        beneficiary: r.publicKey,
+        totalAmount: new BN(leafAmount * (i + 1)),
+        startTime: new BN(i < 2 ? now - 200 : now),
+        cliffTime: new BN(i < 2 ? now - 150 : now + 5000),
+        endTime: new BN(i < 2 ? now - 100 : now + 10000),
+        scheduleKind: { linear: {} },
+        periodSeconds: new BN(0),
+        schedulePoints: [],
+        cliffAmount: new BN(0),
+        accrueFromCliff: false,
+      }));
+      // A second, fully vested leaf for the second recipient
+      leaves.push({ ...leaves[1], totalAmount: new BN(leafAmount * 4) });
+      tree = buildMerkleTree(leaves.map(hashMerkleLeaf));
+
+      ({ pda: merklePda, vault: merkleVault } = await createDistributor(
+        merkleSeed,
+        tree.root,
+        leafAmount * 10
+      ));
     });
 
-    it("T-05: multiple vestings for same beneficiary+mint", async () => {
-      const secondSeed = new BN(300);
-      const [secondPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("vesting"),
-          beneficiary.publicKey.toBuffer(),
-          mint.toBuffer(),
-          secondSeed.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
+    it("T-220: a recipient claims with a valid proof", async () => {
+      await claimMerkle(1, tree.proofs[1]);
+
+      const ata = await getAssociatedTokenAddress(mint, recipients[1].publicKey);
+      expect(Number((await getAccount(connection, ata)).amount)).to.equal(leafAmount * 2);
+      const distributor = await program.account.merkleVesting.fetch(merklePda);
+      expect(distributor.releasedAmount.toNumber()).to.equal(leafAmount * 2);
+    });
+
+    it("T-221: a proof for another leaf is rejected", async () => {
+      try {
+        await claimMerkle(0, tree.proofs[1]);
+        expect.fail("Should have failed: wrong proof");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("InvalidMerkleProof");
+      }
+    });
+
+    it("T-222: only the leaf's beneficiary can claim it", async () => {
+      try {
+        await claimMerkle(0, tree.proofs[0], recipients[1]);
+        expect.fail("Should have failed: not the leaf's beneficiary");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("UnauthorizedBeneficiary");
+      }
+    });
+
+    it("T-223: later claims need no proof and follow the leaf's schedule", async () => {
+      // Fully claimed already: the stored leaf hash is enough, nothing left to claim
+      try {
+        await claimMerkle(1, []);
+        expect.fail("Should have failed: nothing to claim");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("NothingToClaim");
+      }
+
+      // Before the cliff, a valid proof still yields nothing
+      try {
+        await claimMerkle(2, tree.proofs[2]);
+        expect.fail("Should have failed: before the cliff");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("NothingToClaim");
+      }
+    });
+
+    it("T-224: a recipient listed in two leaves claims both", async () => {
+      await claimMerkle(3, tree.proofs[3], recipients[1]);
+
+      const ata = await getAssociatedTokenAddress(mint, recipients[1].publicKey);
+      expect(Number((await getAccount(connection, ata)).amount)).to.equal(leafAmount * 6);
+      const status = await program.account.claimStatus.fetch(claimStatusPda(3, recipients[1]));
+      expect(status.releasedAmount.toNumber()).to.equal(leafAmount * 4);
+    });
+
+    it("T-225: an unsettled distributor without a clawback time stays open", async () => {
+      try {
+        await closeDistributor(merklePda, merkleVault);
+        expect.fail("Should have failed: leaves still unclaimed");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("VestingNotComplete");
+      }
+
+      // Claim statuses stay until the distributor is gone
+      try {
+        await closeClaimStatus(1, recipients[1], merklePda);
+        expect.fail("Should have failed: distributor still open");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("InvalidStatus");
+      }
+    });
+
+    it("T-226: after the clawback time the admin reclaims what is unclaimed", async () => {
+      // Only the second recipient's first leaf, claimed in full; one leaf's worth is never claimed
+      const { pda, vault } = await createDistributor(
+        new BN(2201),
+        hashMerkleLeaf(leaves[1]),
+        leafAmount * 3,
+        (await clusterNow()) + 3
       );
-      const secondVault = await getAssociatedTokenAddress(
-        mint,
-        secondPda,
-        true
+      await program.methods
+        .claimMerkle(leaves[1], [], null)
+        .accountsPartial({
+          beneficiary: recipients[1].publicKey,
+          mint,
+          merkleVesting: pda,
+          vault,
+          claimStatus: claimStatusPda(1, recipients[1], pda),
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([recipients[1]])
+        .rpc();
+
+      await new Promise((r) => setTimeout(r, 4000));
+
+      const adminBefore = Number((await getAccount(connection, adminAta)).amount);
+      await closeDistributor(pda, vault);
+      const adminAfter = Number((await getAccount(connection, adminAta)).amount);
+      expect(adminAfter - adminBefore).to.equal(leafAmount);
+      expect(await connection.getAccountInfo(pda)).to.be.null;
+      expect(await connection.getAccountInfo(vault)).to.be.null;
+
+      // With the distributor closed, the recipient gets the claim status rent back
+      const status = claimStatusPda(1, recipients[1], pda);
+      await closeClaimStatus(1, recipients[1], pda);
+      expect(await connection.getAccountInfo(status)).to.be.null;
+    });
+  });
+
+  // ─────────────────────────────────────────
+  // TR-23: NFT Receipt
+  // ─────────────────────────────────────────
+
+  describe("TR-23: NFT Receipt", () => {
+    const receiptAmount = 1_000 * 10 ** decimals;
+    const receiptSeed = new BN(2300);
+    const receiptMint = Keypair.generate();
+    const newHolder = Keypair.generate();
+    let receiptPda: PublicKey;
+    let receiptVault: PublicKey;
+    let holderReceiptAta: PublicKey;
+
+    const createWithReceipt = async (pdaSeed: BN, nft: Keypair, revocable: boolean) => {
+      const now = await clusterNow();
+
+      // Already fully vested
+      const { pda } = await createGrant(
+        {
+          seed: pdaSeed,
+          totalAmount: new BN(receiptAmount),
+          startTime: new BN(now - 200),
+          cliffTime: new BN(now - 150),
+          endTime: new BN(now - 100),
+          revocable,
+        },
+        {
+          receiptMint: nft.publicKey,
+          receiptTokenAccount: getAssociatedTokenAddressSync(
+            nft.publicKey,
+            beneficiary.publicKey,
+            false,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        },
+        [nft]
+      );
+      return pda;
+    };
+
+    const claimAs = (claimer: Keypair, receiptTokenAccount: PublicKey) =>
+      program.methods
+        .claim(null)
+        .accountsPartial({
+          claimer: claimer.publicKey,
+          beneficiary: claimer.publicKey,
+          mint,
+          vestingAccount: receiptPda,
+          vault: receiptVault,
+          beneficiaryTokenAccount: getAssociatedTokenAddressSync(mint, claimer.publicKey),
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          receiptTokenAccount,
+        })
+        .signers([claimer])
+        .rpc();
+
+    before(async () => {
+      await airdropSol(connection, newHolder.publicKey, LAMPORTS_PER_SOL);
+
+      receiptPda = await createWithReceipt(receiptSeed, receiptMint, false);
+      receiptVault = await getAssociatedTokenAddress(mint, receiptPda, true);
+      holderReceiptAta = getAssociatedTokenAddressSync(
+        receiptMint.publicKey,
+        beneficiary.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
       );
 
       await program.methods
-        .createVesting(
-          secondSeed,
-          new BN(totalAmount),
-          new BN(startTime),
-          new BN(cliffTime),
-          new BN(endTime)
+        .deposit()
+        .accountsPartial({
+          admin: admin.publicKey,
+          mint,
+          vestingAccount: receiptPda,
+          vault: receiptVault,
+          adminTokenAccount: adminAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("T-230: mints a 1-of-1 receipt to the beneficiary", async () => {
+      const vesting = await program.account.vestingAccount.fetch(receiptPda);
+      expect(vesting.receiptMint.toString()).to.equal(receiptMint.publicKey.toString());
+
+      const nft = await getMint(connection, receiptMint.publicKey, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(nft.decimals).to.equal(0);
+      expect(Number(nft.supply)).to.equal(1);
+      expect(nft.mintAuthority).to.be.null;
+      expect(getMintCloseAuthority(nft)?.closeAuthority.toString()).to.equal(receiptPda.toString());
+
+      const holding = await getAccount(connection, holderReceiptAta, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(holding.amount)).to.equal(1);
+    });
+
+    it("T-231: a revocable vesting cannot have a receipt", async () => {
+      try {
+        await createWithReceipt(new BN(2301), Keypair.generate(), true);
+        expect.fail("Should have failed: revocable vesting with receipt");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("NotAllowedWithReceipt");
+      }
+    });
+
+    it("T-232: beneficiary-only paths are disabled while a receipt exists", async () => {
+      try {
+        await program.methods
+          .setClaimDelegate(newHolder.publicKey)
+          .accountsPartial({
+            beneficiary: beneficiary.publicKey,
+            vestingAccount: receiptPda,
+          })
+          .signers([beneficiary])
+          .rpc();
+        expect.fail("Should have failed: vesting has a receipt");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("NotAllowedWithReceipt");
+      }
+    });
+
+    it("T-233: the receipt holder claims after the NFT changes hands", async () => {
+      const newHolderReceiptAta = (
+        await getOrCreateAssociatedTokenAccount(
+          connection,
+          admin,
+          receiptMint.publicKey,
+          newHolder.publicKey,
+          false,
+          undefined,
+          undefined,
+          TOKEN_2022_PROGRAM_ID
         )
+      ).address;
+      await transferChecked(
+        connection,
+        beneficiary,
+        holderReceiptAta,
+        receiptMint.publicKey,
+        newHolderReceiptAta,
+        beneficiary,
+        1,
+        0,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      // The previous holder no longer qualifies
+      try {
+        await claimAs(beneficiary, holderReceiptAta);
+        expect.fail("Should have failed: receipt was transferred");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code).to.equal("InvalidReceipt");
+      }
+
+      await claimAs(newHolder, newHolderReceiptAta);
+
+      const vesting = await program.account.vestingAccount.fetch(receiptPda);
+      expect(vesting.beneficiary.toString()).to.equal(newHolder.publicKey.toString());
+      expect(vesting.releasedAmount.toNumber()).to.equal(receiptAmount);
+      const received = await getAccount(
+        connection,
+        getAssociatedTokenAddressSync(mint, newHolder.publicKey)
+      );
+      expect(Number(received.amount)).to.equal(receiptAmount);
+    });
+
+    it("T-234: closing the vesting burns the receipt and closes its mint", async () => {
+      const newHolderReceiptAta = getAssociatedTokenAddressSync(
+        receiptMint.publicKey,
+        newHolder.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await program.methods
+        .closeVesting()
         .accountsPartial({
           admin: admin.publicKey,
-          beneficiary: beneficiary.publicKey,
           mint,
-          vestingAccount: secondPda,
-          vault: secondVault,
-          systemProgram: SystemProgram.programId,
+          vestingAccount: receiptPda,
+          vault: receiptVault,
+          adminTokenAccount: adminAta,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          receiptMint: receiptMint.publicKey,
+          receiptTokenAccount: newHolderReceiptAta,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
         })
         .signers([admin])
         .rpc();
 
-      // Both vestings should exist
-      const v1 = await program.account.vestingAccount.fetch(vestingPda);
-      const v2 = await program.account.vestingAccount.fetch(secondPda);
-      expect(v1.seed.toNumber()).to.equal(1);
-      expect(v2.seed.toNumber()).to.equal(300);
+      expect(await connection.getAccountInfo(receiptMint.publicKey)).to.be.null;
+      const holding = await getAccount(connection, newHolderReceiptAta, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(holding.amount)).to.equal(0);
     });
   });
 });
@@ -1169,3 +3048,76 @@ async function airdropSol(
     ...latestBlockhash,
   });
 }
+
+/** Create the Anchor program config (unpaused, no fees) unless an earlier suite did */
+async function ensureAnchorConfig(program: Program<AnchorVesting>) {
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  if (await program.provider.connection.getAccountInfo(config)) return;
+
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+  await program.methods
+    .initializeConfig(false, 0, program.provider.publicKey, new BN(0), 0, [])
+    .accountsPartial({
+      authority: program.provider.publicKey,
+      config,
+      program: program.programId,
+      programData,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
+/** sha256(0x00 || borsh(leaf)) for a leaf without custom schedule points */
+function hashMerkleLeaf(leaf: any): Buffer {
+  const data = Buffer.alloc(32 + 8 * 4 + 1 + 8 + 4 + 8 + 1);
+  let offset = 0;
+  leaf.beneficiary.toBuffer().copy(data, offset);
+  offset += 32;
+  for (const field of [leaf.totalAmount, leaf.startTime, leaf.cliffTime, leaf.endTime]) {
+    data.writeBigInt64LE(BigInt(field.toString()), offset);
+    offset += 8;
+  }
+  data[offset++] = Object.keys(leaf.scheduleKind)[0] === "periodic" ? 1 : 0;
+  data.writeBigUInt64LE(BigInt(leaf.periodSeconds.toString()), offset);
+  offset += 8;
+  data.writeUInt32LE(0, offset); // schedule_points: empty vec
+  offset += 4;
+  data.writeBigUInt64LE(BigInt(leaf.cliffAmount.toString()), offset);
+  offset += 8;
+  data[offset] = leaf.accrueFromCliff ? 1 : 0;
+  return createHash("sha256").update(Buffer.concat([Buffer.from([0]), data])).digest();
+}
+
+/** Sorted-pair merkle tree (inner nodes: sha256(0x01 || min || max)); odd nodes move up */
+function buildMerkleTree(leafHashes: Buffer[]): { root: Buffer; proofs: Buffer[][] } {
+  const proofs: Buffer[][] = leafHashes.map(() => []);
+  let level = leafHashes.map((hash, i) => ({ hash, members: [i] }));
+  while (level.length > 1) {
+    const next: typeof level = [];
+    for (let i = 0; i < level.length; i += 2) {
+      const left = level[i];
+      const right = level[i + 1];
+      if (!right) {
+        next.push(left);
+        continue;
+      }
+      left.members.forEach((m) => proofs[m].push(right.hash));
+      right.members.forEach((m) => proofs[m].push(left.hash));
+      const [a, b] = Buffer.compare(left.hash, right.hash) <= 0
+        ? [left.hash, right.hash]
+        : [right.hash, left.hash];
+      next.push({
+        hash: createHash("sha256").update(Buffer.concat([Buffer.from([1]), a, b])).digest(),
+        members: [...left.members, ...right.members],
+      });
+    }
+    level = next;
+  }
+  return { root: level[0].hash, proofs };
+}
@@ -24,6 +24,14 @@ import BN from "bn.js";
 const PINOCCHIO_PROGRAM_ID = new PublicKey(
   "EzRUZpW3CsvnKzEUiF7fAPHyHWsv2D3ERR482BPKHwYk"
 );
+const [PINOCCHIO_CONFIG] = PublicKey.findProgramAddressSync(
+  [Buffer.from("config")],
+  PINOCCHIO_PROGRAM_ID
+);
+
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
 
 describe("CU Comparison: Anchor vs Pinocchio", () => {
   const provider = anchor.AnchorProvider.env();
@@ -51,11 +59,16 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
   let pinocchioVault: PublicKey;
 
   let adminAta: PublicKey;
+  let anchorCreateTx: string;
+  let pinocchioCreateTx: string;
   let startTime: number;
   let cliffTime: number;
   let endTime: number;
 
   before(async () => {
+    await ensureAnchorConfig(program);
+    await ensurePinocchioConfig(provider);
+
     const airdropAmount = 20 * LAMPORTS_PER_SOL;
     await Promise.all([
       airdropSol(connection, admin.publicKey, airdropAmount),
@@ -76,6 +89,7 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
     [anchorPda, anchorBump] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("vesting"),
+        admin.publicKey.toBuffer(),
         beneficiary.publicKey.toBuffer(),
         mint.toBuffer(),
         anchorSeed.toArrayLike(Buffer, "le", 8),
@@ -87,6 +101,7 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
     [pinocchioPda, pinocchioBump] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("vesting"),
+        admin.publicKey.toBuffer(),
         beneficiary.publicKey.toBuffer(),
         mint.toBuffer(),
         pinocchioSeed.toArrayLike(Buffer, "le", 8),
@@ -106,13 +121,21 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
   it("compares create_vesting CU consumption", async () => {
     // --- Anchor ---
     const anchorTx = await program.methods
-      .createVesting(
-        anchorSeed,
-        new BN(totalAmount),
-        new BN(startTime),
-        new BN(cliffTime),
-        new BN(endTime)
-      )
+      .createVesting({
+        seed: anchorSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(startTime),
+        cliffTime: new BN(cliffTime),
+        endTime: new BN(endTime),
+        revocable: false,
+        scheduleKind: { linear: {} },
+        periodSeconds: new BN(0),
+        schedulePoints: [],
+        cliffAmount: new BN(0),
+        accrueFromCliff: false,
+        allowPermissionlessClaim: false,
+        transferable: false,
+      })
       .accountsPartial({
         admin: admin.publicKey,
         beneficiary: beneficiary.publicKey,
@@ -126,10 +149,11 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
       .signers([admin])
       .rpc();
 
+    anchorCreateTx = anchorTx;
     const anchorCU = await getComputeUnits(connection, anchorTx);
 
     // --- Pinocchio ---
-    const pinData = Buffer.alloc(42);
+    const pinData = Buffer.alloc(64);
     pinData[0] = 0;
     pinData.writeBigUInt64LE(BigInt(pinocchioSeed.toString()), 1);
     pinData.writeBigUInt64LE(BigInt(totalAmount.toString()), 9);
@@ -137,6 +161,14 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
     pinData.writeBigInt64LE(BigInt(cliffTime.toString()), 25);
     pinData.writeBigInt64LE(BigInt(endTime.toString()), 33);
     pinData[41] = pinocchioBump;
+    pinData[42] = 0; // revocable = false
+    pinData[43] = 0; // schedule_kind = Linear
+    pinData.writeBigUInt64LE(BigInt(0), 44); // period_seconds
+    pinData.writeBigUInt64LE(BigInt(0), 52); // cliff_amount
+    pinData[60] = 0; // accrue_from_cliff = false
+    pinData[61] = 0; // allow_permissionless_claim = false
+    pinData[62] = 0; // transferable = false
+    pinData[63] = 0; // no custom schedule points
 
     const pinIx = new TransactionInstruction({
       programId: PINOCCHIO_PROGRAM_ID,
@@ -145,7 +177,11 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
         { pubkey: beneficiary.publicKey, isSigner: false, isWritable: false },
         { pubkey: mint, isSigner: false, isWritable: false },
         { pubkey: pinocchioPda, isSigner: false, isWritable: true },
+        { pubkey: pinocchioVault, isSigner: false, isWritable: true },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
       ],
       data: pinData,
     });
@@ -156,6 +192,7 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
       [admin]
     );
 
+    pinocchioCreateTx = pinTxSig;
     const pinocchioCU = await getComputeUnits(connection, pinTxSig);
 
     console.log("\n  === Create Vesting CU Comparison ===");
@@ -171,15 +208,7 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
   });
 
   it("compares deposit CU consumption", async () => {
-    // Create vault ATA for pinocchio (allowOwnerOffCurve = true for PDA)
-    await getOrCreateAssociatedTokenAccount(
-      connection,
-      admin,
-      mint,
-      pinocchioPda,
-      true // allowOwnerOffCurve
-    );
-
+    // Both programs created their vault ATA in create_vesting
     // --- Anchor deposit ---
     const anchorTx = await program.methods
       .deposit()
@@ -203,10 +232,11 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
       keys: [
         { pubkey: admin.publicKey, isSigner: true, isWritable: true },
         { pubkey: mint, isSigner: false, isWritable: false },
-        { pubkey: pinocchioPda, isSigner: false, isWritable: false },
+        { pubkey: pinocchioPda, isSigner: false, isWritable: true },
         { pubkey: pinocchioVault, isSigner: false, isWritable: true },
         { pubkey: adminAta, isSigner: false, isWritable: true },
         { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
       ],
       data: Buffer.from([1]),
     });
@@ -240,11 +270,16 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
     expect(pinAccount).to.not.be.null;
     const pinData = pinAccount!.data;
 
-    const pinAdmin = new PublicKey(pinData.subarray(0, 32));
-    const pinBeneficiary = new PublicKey(pinData.subarray(32, 64));
-    const pinMint = new PublicKey(pinData.subarray(64, 96));
-    const pinTotalAmount = Number(pinData.readBigUInt64LE(96));
-    const pinReleasedAmount = Number(pinData.readBigUInt64LE(104));
+    // Pinocchio writes the same discriminator as Anchor's VestingAccount
+    const anchorAccount = await connection.getAccountInfo(anchorPda);
+    expect(pinData.subarray(0, 8).equals(anchorAccount!.data.subarray(0, 8))).to.equal(true);
+    expect(pinData.length).to.equal(anchorAccount!.data.length);
+
+    const pinAdmin = new PublicKey(pinData.subarray(8, 40));
+    const pinBeneficiary = new PublicKey(pinData.subarray(40, 72));
+    const pinMint = new PublicKey(pinData.subarray(72, 104));
+    const pinTotalAmount = Number(pinData.readBigUInt64LE(104));
+    const pinReleasedAmount = Number(pinData.readBigUInt64LE(112));
 
     // Both should have same logical state
     expect(anchorVesting.admin.toString()).to.equal(pinAdmin.toString());
@@ -254,12 +289,312 @@ describe("CU Comparison: Anchor vs Pinocchio", () => {
     expect(anchorVesting.releasedAmount.toNumber()).to.equal(pinReleasedAmount);
     // Time params should be the same since we used the same inputs
     expect(anchorVesting.startTime.toNumber()).to.equal(
-      Number(pinData.readBigInt64LE(112))
+      Number(pinData.readBigInt64LE(120))
     );
     expect(anchorVesting.endTime.toNumber()).to.equal(
-      Number(pinData.readBigInt64LE(128))
+      Number(pinData.readBigInt64LE(136))
     );
   });
+
+  it("emits VestingCreated payloads decodable by the same Anchor coder", async () => {
+    const [anchorEvent] = await getEvents(connection, program, anchorCreateTx);
+    const [pinEvent] = await getEvents(connection, program, pinocchioCreateTx);
+
+    expect(anchorEvent.name).to.equal("vestingCreated");
+    expect(pinEvent.name).to.equal("vestingCreated");
+    expect(anchorEvent.data.vesting.toString()).to.equal(anchorPda.toString());
+    expect(pinEvent.data.vesting.toString()).to.equal(pinocchioPda.toString());
+
+    // Every field other than the vesting address must match
+    for (const field of [
+      "admin",
+      "beneficiary",
+      "mint",
+      "seed",
+      "totalAmount",
+      "startTime",
+      "cliffTime",
+      "endTime",
+      "revocable",
+      "creationFeeLamports",
+    ]) {
+      expect(pinEvent.data[field].toString()).to.equal(
+        anchorEvent.data[field].toString()
+      );
+    }
+  });
+
+  describe("Release parity", () => {
+    const amount = 1_000 * 10 ** decimals;
+    // Anchor enum values and the matching Pinocchio schedule_kind bytes
+    const SCHEDULE_KINDS = {
+      linear: { anchor: { linear: {} }, pinocchio: 0 },
+      periodic: { anchor: { periodic: {} }, pinocchio: 1 },
+      customLinear: { anchor: { customLinear: {} }, pinocchio: 2 },
+      customStep: { anchor: { customStep: {} }, pinocchio: 3 },
+    };
+    let nextSeed = 100;
+    let beneficiaryAta: PublicKey;
+
+    type Schedule = {
+      kind: keyof typeof SCHEDULE_KINDS;
+      start: number;
+      cliff: number;
+      end: number;
+      periodSeconds?: number;
+      cliffAmount?: number;
+      accrueFromCliff?: boolean;
+      points?: [number, number][];
+    };
+
+    async function clusterNow(): Promise<number> {
+      const slot = await connection.getSlot();
+      const blockTime = await connection.getBlockTime(slot);
+      return blockTime || Math.floor(Date.now() / 1000);
+    }
+
+    /** The same funded schedule through both programs' create_and_fund */
+    async function createPair(schedule: Schedule) {
+      const seed = new BN(nextSeed++);
+      const seeds = [
+        Buffer.from("vesting"),
+        admin.publicKey.toBuffer(),
+        beneficiary.publicKey.toBuffer(),
+        mint.toBuffer(),
+        seed.toArrayLike(Buffer, "le", 8),
+      ];
+      const [anchorGrant] = PublicKey.findProgramAddressSync(seeds, program.programId);
+      const [pinGrant, pinBump] = PublicKey.findProgramAddressSync(seeds, PINOCCHIO_PROGRAM_ID);
+      const pair = {
+        anchorGrant,
+        anchorGrantVault: await getAssociatedTokenAddress(mint, anchorGrant, true),
+        pinGrant,
+        pinGrantVault: await getAssociatedTokenAddress(mint, pinGrant, true),
+      };
+      const points = schedule.points ?? [];
+
+      await program.methods
+        .createAndFundVesting({
+          seed,
+          totalAmount: new BN(amount),
+          startTime: new BN(schedule.start),
+          cliffTime: new BN(schedule.cliff),
+          endTime: new BN(schedule.end),
+          revocable: false,
+          scheduleKind: SCHEDULE_KINDS[schedule.kind].anchor,
+          periodSeconds: new BN(schedule.periodSeconds ?? 0),
+          schedulePoints: points.map(([timestamp, pointAmount]) => ({
+            timestamp: new BN(timestamp),
+            amount: new BN(pointAmount),
+          })),
+          cliffAmount: new BN(schedule.cliffAmount ?? 0),
+          accrueFromCliff: schedule.accrueFromCliff ?? false,
+          allowPermissionlessClaim: false,
+          transferable: false,
+        })
+        .accountsPartial({
+          admin: admin.publicKey,
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount: anchorGrant,
+          vault: pair.anchorGrantVault,
+          adminTokenAccount: adminAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      // create_and_fund_vesting (instruction 6), same layout as create_vesting
+      const pinData = Buffer.alloc(64 + 16 * points.length);
+      pinData[0] = 6;
+      pinData.writeBigUInt64LE(BigInt(seed.toString()), 1);
+      pinData.writeBigUInt64LE(BigInt(amount), 9);
+      pinData.writeBigInt64LE(BigInt(schedule.start), 17);
+      pinData.writeBigInt64LE(BigInt(schedule.cliff), 25);
+      pinData.writeBigInt64LE(BigInt(schedule.end), 33);
+      pinData[41] = pinBump;
+      pinData[42] = 0; // revocable = false
+      pinData[43] = SCHEDULE_KINDS[schedule.kind].pinocchio;
+      pinData.writeBigUInt64LE(BigInt(schedule.periodSeconds ?? 0), 44);
+      pinData.writeBigUInt64LE(BigInt(schedule.cliffAmount ?? 0), 52);
+      pinData[60] = schedule.accrueFromCliff ? 1 : 0;
+      pinData[61] = 0; // allow_permissionless_claim = false
+      pinData[62] = 0; // transferable = false
+      pinData[63] = points.length;
+      points.forEach(([timestamp, pointAmount], i) => {
+        pinData.writeBigInt64LE(BigInt(timestamp), 64 + 16 * i);
+        pinData.writeBigUInt64LE(BigInt(pointAmount), 72 + 16 * i);
+      });
+
+      const pinIx = new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: admin.publicKey, isSigner: true, isWritable: true },
+          { pubkey: beneficiary.publicKey, isSigner: false, isWritable: false },
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: pinGrant, isSigner: false, isWritable: true },
+          { pubkey: pair.pinGrantVault, isSigner: false, isWritable: true },
+          { pubkey: adminAta, isSigner: false, isWritable: true },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data: pinData,
+      });
+      await sendAndConfirmTransaction(connection, new Transaction().add(pinIx), [admin]);
+
+      return pair;
+    }
+
+    /**
+     * Claim from both vestings in one transaction, so both programs see the
+     * same Clock; `undefined` claims everything claimable
+     */
+    async function claimBoth(
+      pair: Awaited<ReturnType<typeof createPair>>,
+      claimAmount?: number
+    ) {
+      const anchorIx = await program.methods
+        .claim(claimAmount === undefined ? null : new BN(claimAmount))
+        .accountsPartial({
+          claimer: beneficiary.publicKey,
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount: pair.anchorGrant,
+          vault: pair.anchorGrantVault,
+          beneficiaryTokenAccount: beneficiaryAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .instruction();
+
+      const pinData = Buffer.alloc(claimAmount === undefined ? 1 : 9);
+      pinData[0] = 2;
+      if (claimAmount !== undefined) pinData.writeBigUInt64LE(BigInt(claimAmount), 1);
+      const pinIx = new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: beneficiary.publicKey, isSigner: true, isWritable: true },
+          { pubkey: beneficiary.publicKey, isSigner: false, isWritable: false },
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: pair.pinGrant, isSigner: false, isWritable: true },
+          { pubkey: pair.pinGrantVault, isSigner: false, isWritable: true },
+          { pubkey: beneficiaryAta, isSigner: false, isWritable: true },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data: pinData,
+      });
+
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(anchorIx, pinIx),
+        [beneficiary]
+      );
+    }
+
+    /** released_amount of both vestings, asserted equal */
+    async function expectSameReleased(pair: Awaited<ReturnType<typeof createPair>>) {
+      const anchorReleased = (
+        await program.account.vestingAccount.fetch(pair.anchorGrant)
+      ).releasedAmount.toNumber();
+      const pinReleased = Number(
+        (await connection.getAccountInfo(pair.pinGrant))!.data.readBigUInt64LE(112)
+      );
+      expect(pinReleased).to.equal(anchorReleased);
+      return anchorReleased;
+    }
+
+    before(async () => {
+      beneficiaryAta = (
+        await getOrCreateAssociatedTokenAccount(connection, admin, mint, beneficiary.publicKey)
+      ).address;
+    });
+
+    it("releases the same cliff amount and cliff-based accrual", async () => {
+      const now = await clusterNow();
+      const pair = await createPair({
+        kind: "linear",
+        start: now - 100,
+        cliff: now - 50,
+        end: now + 100,
+        cliffAmount: amount / 5,
+        accrueFromCliff: true,
+      });
+
+      await claimBoth(pair);
+      const released = await expectSameReleased(pair);
+      expect(released).to.be.greaterThan(amount / 5).and.lessThan(amount);
+    });
+
+    it("releases the same periodic steps", async () => {
+      const now = await clusterNow();
+      const pair = await createPair({
+        kind: "periodic",
+        start: now - 100,
+        cliff: now - 100,
+        end: now + 200,
+        periodSeconds: 60,
+      });
+
+      await claimBoth(pair);
+      const released = await expectSameReleased(pair);
+      // One or two of the five periods have elapsed
+      expect(released % (amount / 5)).to.equal(0);
+      expect(released).to.be.greaterThan(0).and.lessThan(amount);
+    });
+
+    for (const kind of ["customLinear", "customStep"] as const) {
+      it(`releases the same ${kind} custom schedule`, async () => {
+        const now = await clusterNow();
+        const pair = await createPair({
+          kind,
+          start: now - 100,
+          cliff: now - 100,
+          end: now + 200,
+          points: [
+            [now - 100, amount / 4],
+            [now + 100, amount / 2],
+            [now + 200, amount],
+          ],
+        });
+
+        await claimBoth(pair);
+        const released = await expectSameReleased(pair);
+        if (kind === "customStep") {
+          expect(released).to.equal(amount / 4);
+        } else {
+          expect(released).to.be.greaterThan(amount / 4).and.lessThan(amount / 2);
+        }
+      });
+    }
+
+    it("tracks partial claims the same way", async () => {
+      const now = await clusterNow();
+      const pair = await createPair({
+        kind: "linear",
+        start: now - 100,
+        cliff: now - 100,
+        end: now + 100,
+      });
+
+      await claimBoth(pair, amount / 10);
+      expect(await expectSameReleased(pair)).to.equal(amount / 10);
+
+      await claimBoth(pair, amount / 10);
+      expect(await expectSameReleased(pair)).to.equal(amount / 5);
+
+      // Then everything else that is claimable
+      await claimBoth(pair);
+      expect(await expectSameReleased(pair)).to.be.greaterThan(amount / 5);
+    });
+  });
 });
 
 // ─────────────────────────────────────────────
@@ -284,6 +619,22 @@ async function getComputeUnits(
   return 0;
 }
 
+async function getEvents(
+  connection: anchor.web3.Connection,
+  program: Program<AnchorVesting>,
+  txSig: string
+): Promise<any[]> {
+  const tx = await connection.getTransaction(txSig, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const prefix = "Program data: ";
+  return (tx?.meta?.logMessages ?? [])
+    .filter((line) => line.startsWith(prefix))
+    .map((line) => program.coder.events.decode(line.slice(prefix.length)))
+    .filter((event) => event !== null);
+}
+
 async function airdropSol(
   connection: anchor.web3.Connection,
   publicKey: PublicKey,
@@ -293,3 +644,57 @@ async function airdropSol(
   const latestBlockhash = await connection.getLatestBlockhash();
   await connection.confirmTransaction({ signature: sig, ...latestBlockhash });
 }
+
+/** Create the Anchor program config (unpaused, no fees) unless an earlier suite did */
+async function ensureAnchorConfig(program: Program<AnchorVesting>) {
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  if (await program.provider.connection.getAccountInfo(config)) return;
+
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+  await program.methods
+    .initializeConfig(false, 0, program.provider.publicKey, new BN(0), 0, [])
+    .accountsPartial({
+      authority: program.provider.publicKey,
+      config,
+      program: program.programId,
+      programData,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
+/** Create the Pinocchio program config (unpaused, no fees) unless an earlier suite did */
+async function ensurePinocchioConfig(provider: anchor.AnchorProvider) {
+  if (await provider.connection.getAccountInfo(PINOCCHIO_CONFIG)) return;
+
+  const [, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    PINOCCHIO_PROGRAM_ID
+  );
+  const [programData] = PublicKey.findProgramAddressSync(
+    [PINOCCHIO_PROGRAM_ID.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+  const ix = new TransactionInstruction({
+    programId: PINOCCHIO_PROGRAM_ID,
+    keys: [
+      { pubkey: provider.publicKey, isSigner: true, isWritable: true },
+      { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: true },
+      { pubkey: programData, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    // initialize_config, unpaused, treasury = provider wallet, no fees, no exemptions
+    data: Buffer.concat([
+      Buffer.from([13, 0, 0, bump]),
+      provider.publicKey.toBuffer(),
+      Buffer.alloc(8 + 2 + 1),
+    ]),
+  });
+  await provider.sendAndConfirm(new Transaction().add(ix));
+}
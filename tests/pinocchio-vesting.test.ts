@@ -10,11 +10,14 @@ import {
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
   createMint,
   mintTo,
   getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
   getAccount,
   getOrCreateAssociatedTokenAccount,
+  createAccount,
 } from "@solana/spl-token";
 import { expect } from "chai";
 import BN from "bn.js";
@@ -22,6 +25,23 @@ import BN from "bn.js";
 const PINOCCHIO_PROGRAM_ID = new PublicKey(
   "EzRUZpW3CsvnKzEUiF7fAPHyHWsv2D3ERR482BPKHwYk"
 );
+const [PINOCCHIO_CONFIG] = PublicKey.findProgramAddressSync(
+  [Buffer.from("config")],
+  PINOCCHIO_PROGRAM_ID
+);
+
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+// Pre-upgrade accounts preloaded from tests/fixtures (see Anchor.toml):
+// a 145-byte legacy vesting (seed 7, 1_000_000 tokens, fully vested) with a
+// vault holding everything, and a 465-byte headerless vesting
+const LEGACY_VESTING = new PublicKey("DfSmdbkTpe9oaBKCVp2BRwyHG9wVgtQ21nrYrTtLSAzh");
+const LEGACY_VESTING_VAULT = new PublicKey("6FZm9RCbm1EHFNzAA8p8pDKV4Vm1epBR5Cvok24fkSRN");
+const LEGACY_ADMIN = new PublicKey("vV6ZTKDZrmqnRMj7TL1HvtFXA3ooxmKgoW9kw4BC61i");
+const LEGACY_BENEFICIARY = new PublicKey("CEVbxh4zuff5u6NNYivsvhV5M3vXmVuZBhjhztbZABYM");
+const HEADERLESS_VESTING = new PublicKey("FoFK8u2RWpHUsSkZ34RcfAH4wJJ8oURNyX4eK8k2rHgg");
 
 describe("pinocchio-vesting", () => {
   const provider = anchor.AnchorProvider.env();
@@ -63,9 +83,14 @@ describe("pinocchio-vesting", () => {
       mint: PublicKey;
       vestingAccount: PublicKey;
       systemProgram: PublicKey;
-    }
+    },
+    options: {
+      revocable?: boolean;
+      allowPermissionlessClaim?: boolean;
+      transferable?: boolean;
+    } = {}
   ): TransactionInstruction {
-    const data = Buffer.alloc(42);
+    const data = Buffer.alloc(64);
     data[0] = 0; // instruction index
     data.writeBigUInt64LE(BigInt(seed.toString()), 1);
     data.writeBigUInt64LE(BigInt(totalAmount.toString()), 9);
@@ -73,6 +98,14 @@ describe("pinocchio-vesting", () => {
     data.writeBigInt64LE(BigInt(cliffTime.toString()), 25);
     data.writeBigInt64LE(BigInt(endTime.toString()), 33);
     data[41] = bump;
+    data[42] = options.revocable ? 1 : 0;
+    data[43] = 0; // schedule_kind = Linear
+    data.writeBigUInt64LE(BigInt(0), 44); // period_seconds
+    data.writeBigUInt64LE(BigInt(0), 52); // cliff_amount
+    data[60] = 0; // accrue_from_cliff = false
+    data[61] = options.allowPermissionlessClaim ? 1 : 0;
+    data[62] = options.transferable ? 1 : 0;
+    data[63] = 0; // no custom schedule points
 
     return new TransactionInstruction({
       programId: PINOCCHIO_PROGRAM_ID,
@@ -81,7 +114,16 @@ describe("pinocchio-vesting", () => {
         { pubkey: accounts.beneficiary, isSigner: false, isWritable: false },
         { pubkey: accounts.mint, isSigner: false, isWritable: false },
         { pubkey: accounts.vestingAccount, isSigner: false, isWritable: true },
+        // Vault ATA, created by the program
+        {
+          pubkey: getAssociatedTokenAddressSync(accounts.mint, accounts.vestingAccount, true),
+          isSigner: false,
+          isWritable: true,
+        },
         { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
       ],
       data,
     });
@@ -102,16 +144,18 @@ describe("pinocchio-vesting", () => {
       keys: [
         { pubkey: accounts.admin, isSigner: true, isWritable: true },
         { pubkey: accounts.mint, isSigner: false, isWritable: false },
-        { pubkey: accounts.vestingAccount, isSigner: false, isWritable: false },
+        { pubkey: accounts.vestingAccount, isSigner: false, isWritable: true },
         { pubkey: accounts.vault, isSigner: false, isWritable: true },
         { pubkey: accounts.adminTokenAccount, isSigner: false, isWritable: true },
         { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+        { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
       ],
       data,
     });
   }
 
   function buildClaimIx(accounts: {
+    claimer?: PublicKey;
     beneficiary: PublicKey;
     mint: PublicKey;
     vestingAccount: PublicKey;
@@ -124,18 +168,100 @@ describe("pinocchio-vesting", () => {
     return new TransactionInstruction({
       programId: PINOCCHIO_PROGRAM_ID,
       keys: [
-        { pubkey: accounts.beneficiary, isSigner: true, isWritable: true },
+        { pubkey: accounts.claimer ?? accounts.beneficiary, isSigner: true, isWritable: true },
+        { pubkey: accounts.beneficiary, isSigner: false, isWritable: false },
         { pubkey: accounts.mint, isSigner: false, isWritable: false },
         { pubkey: accounts.vestingAccount, isSigner: false, isWritable: true },
         { pubkey: accounts.vault, isSigner: false, isWritable: true },
+        // Created by the program if missing
         { pubkey: accounts.beneficiaryTokenAccount, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
         { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
       ],
       data,
     });
   }
 
+  async function clusterNow(): Promise<number> {
+    const slot = await connection.getSlot();
+    const blockTime = await connection.getBlockTime(slot);
+    return blockTime || Math.floor(Date.now() / 1000);
+  }
+
+  /** Vesting PDA of `admin` for `beneficiary` over `mint` */
+  function deriveVesting(vestingSeed: BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vesting"),
+        admin.publicKey.toBuffer(),
+        beneficiary.publicKey.toBuffer(),
+        mint.toBuffer(),
+        vestingSeed.toArrayLike(Buffer, "le", 8),
+      ],
+      PINOCCHIO_PROGRAM_ID
+    );
+  }
+
+  /** Create + deposit in one transaction from `admin` to `beneficiary` */
+  async function createFunded(
+    vestingSeed: BN,
+    amount: number,
+    times: { start: number; cliff: number; end: number },
+    options: Parameters<typeof buildCreateVestingIx>[7] = {}
+  ) {
+    const [pda, bump] = deriveVesting(vestingSeed);
+    const pdaVault = getAssociatedTokenAddressSync(mint, pda, true);
+    const createIx = buildCreateVestingIx(
+      vestingSeed,
+      new BN(amount),
+      new BN(times.start),
+      new BN(times.cliff),
+      new BN(times.end),
+      bump,
+      {
+        admin: admin.publicKey,
+        beneficiary: beneficiary.publicKey,
+        mint,
+        vestingAccount: pda,
+        systemProgram: SystemProgram.programId,
+      },
+      options
+    );
+    const depIx = buildDepositIx({
+      admin: admin.publicKey,
+      mint,
+      vestingAccount: pda,
+      vault: pdaVault,
+      adminTokenAccount: adminAta,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(createIx, depIx),
+      [admin]
+    );
+    return { pda, pdaVault };
+  }
+
+  /** Send `ix` and expect it to fail with custom program error `code` */
+  async function expectCustomError(
+    ix: TransactionInstruction,
+    signers: Keypair[],
+    code: number
+  ) {
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(ix), signers);
+      expect.fail(`Should have failed with error ${code}`);
+    } catch (err: any) {
+      expect(err.toString()).to.include(`custom program error: 0x${code.toString(16)}`);
+    }
+  }
+
   before(async () => {
+    await ensurePinocchioConfig(provider);
+
     // Airdrop SOL
     const airdropAmount = 10 * LAMPORTS_PER_SOL;
     await Promise.all([
@@ -160,6 +286,7 @@ describe("pinocchio-vesting", () => {
     [vestingPda, vestingBump] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("vesting"),
+        admin.publicKey.toBuffer(),
         beneficiary.publicKey.toBuffer(),
         mint.toBuffer(),
         seed.toArrayLike(Buffer, "le", 8),
@@ -202,35 +329,33 @@ describe("pinocchio-vesting", () => {
       // Verify by reading raw account data
       const account = await connection.getAccountInfo(vestingPda);
       expect(account).to.not.be.null;
-      expect(account!.data.length).to.equal(145);
+      expect(account!.data.length).to.equal(474);
       expect(account!.owner.toString()).to.equal(PINOCCHIO_PROGRAM_ID.toString());
 
       // Parse stored data
+      // Same layout as Anchor: discriminator, fields, layout version last
       const data = account!.data;
-      const storedAdmin = new PublicKey(data.subarray(0, 32));
-      const storedBeneficiary = new PublicKey(data.subarray(32, 64));
-      const storedMint = new PublicKey(data.subarray(64, 96));
-      const storedTotalAmount = data.readBigUInt64LE(96);
-      const storedReleasedAmount = data.readBigUInt64LE(104);
+      expect(Array.from(data.subarray(0, 8))).to.deep.equal([102, 73, 10, 233, 200, 188, 228, 216]);
+      expect(data[473]).to.equal(1); // layout_version
+      const storedAdmin = new PublicKey(data.subarray(8, 40));
+      const storedBeneficiary = new PublicKey(data.subarray(40, 72));
+      const storedMint = new PublicKey(data.subarray(72, 104));
+      const storedTotalAmount = data.readBigUInt64LE(104);
+      const storedReleasedAmount = data.readBigUInt64LE(112);
 
       expect(storedAdmin.toString()).to.equal(admin.publicKey.toString());
       expect(storedBeneficiary.toString()).to.equal(beneficiary.publicKey.toString());
       expect(storedMint.toString()).to.equal(mint.toString());
       expect(Number(storedTotalAmount)).to.equal(totalAmount);
       expect(Number(storedReleasedAmount)).to.equal(0);
-    });
 
-    it("deposits tokens after creating vault ATA", async () => {
-      // Create vault ATA (since pinocchio program doesn't auto-create it)
-      // allowOwnerOffCurve = true because vestingPda is a PDA (off-curve)
-      await getOrCreateAssociatedTokenAccount(
-        connection,
-        admin,
-        mint,
-        vestingPda,
-        true // allowOwnerOffCurve
-      );
+      // The vault ATA is created alongside the vesting, as in Anchor
+      const vaultAccount = await getAccount(connection, vault);
+      expect(vaultAccount.owner.toString()).to.equal(vestingPda.toString());
+      expect(Number(vaultAccount.amount)).to.equal(0);
+    });
 
+    it("deposits tokens into the vault created with the vesting", async () => {
       const ix = buildDepositIx({
         admin: admin.publicKey,
         mint,
@@ -247,6 +372,130 @@ describe("pinocchio-vesting", () => {
       const vaultAccount = await getAccount(connection, vault);
       expect(Number(vaultAccount.amount)).to.equal(totalAmount);
     });
+
+    it("creates and funds vesting in one transaction", async () => {
+      const fundedSeed = new BN(60);
+      const [fundedPda, fundedBump] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vesting"),
+          admin.publicKey.toBuffer(),
+          beneficiary.publicKey.toBuffer(),
+          mint.toBuffer(),
+          fundedSeed.toArrayLike(Buffer, "le", 8),
+        ],
+        PINOCCHIO_PROGRAM_ID
+      );
+      const fundedVault = await getAssociatedTokenAddress(mint, fundedPda, true);
+
+      // Same data as create (instruction index 6), plus the admin's token account
+      const createIx = buildCreateVestingIx(
+        fundedSeed,
+        new BN(totalAmount),
+        new BN(startTime),
+        new BN(cliffTime),
+        new BN(endTime),
+        fundedBump,
+        {
+          admin: admin.publicKey,
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount: fundedPda,
+          systemProgram: SystemProgram.programId,
+        }
+      );
+      createIx.data[0] = 6;
+      const ix = new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          ...createIx.keys.slice(0, 4),
+          { pubkey: fundedVault, isSigner: false, isWritable: true },
+          { pubkey: adminAta, isSigner: false, isWritable: true },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data: createIx.data,
+      });
+
+      // The program creates the vault itself
+      const tx = new Transaction().add(ix);
+      await sendAndConfirmTransaction(connection, tx, [admin]);
+
+      const account = await connection.getAccountInfo(fundedPda);
+      expect(account!.data[162]).to.equal(1); // status = Funded
+      const vaultAccount = await getAccount(connection, fundedVault);
+      expect(Number(vaultAccount.amount)).to.equal(totalAmount);
+    });
+
+    it("creates and funds a batch of vestings in one transaction", async () => {
+      const batchSeeds = [new BN(62), new BN(63)];
+      const entries = batchSeeds.map((batchSeed) => {
+        const [pda, bump] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vesting"),
+            admin.publicKey.toBuffer(),
+            beneficiary.publicKey.toBuffer(),
+            mint.toBuffer(),
+            batchSeed.toArrayLike(Buffer, "le", 8),
+          ],
+          PINOCCHIO_PROGRAM_ID
+        );
+        const createIx = buildCreateVestingIx(
+          batchSeed,
+          new BN(totalAmount / 10),
+          new BN(startTime),
+          new BN(cliffTime),
+          new BN(endTime),
+          bump,
+          {
+            admin: admin.publicKey,
+            beneficiary: beneficiary.publicKey,
+            mint,
+            vestingAccount: pda,
+            systemProgram: SystemProgram.programId,
+          }
+        );
+        // Each entry is beneficiary + create data (without the instruction index)
+        return {
+          pda,
+          vault: getAssociatedTokenAddressSync(mint, pda, true),
+          data: Buffer.concat([beneficiary.publicKey.toBuffer(), createIx.data.subarray(1)]),
+        };
+      });
+
+      const ix = new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: admin.publicKey, isSigner: true, isWritable: true },
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: adminAta, isSigner: false, isWritable: true },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+          // No fees configured: treasury accounts are present but unused
+          { pubkey: provider.publicKey, isSigner: false, isWritable: true },
+          { pubkey: adminAta, isSigner: false, isWritable: true },
+          ...entries.flatMap((e) => [
+            { pubkey: e.pda, isSigner: false, isWritable: true },
+            { pubkey: e.vault, isSigner: false, isWritable: true },
+          ]),
+        ],
+        data: Buffer.concat([Buffer.from([15, entries.length]), ...entries.map((e) => e.data)]),
+      });
+
+      // Vaults are created by the program
+      const tx = new Transaction().add(ix);
+      await sendAndConfirmTransaction(connection, tx, [admin]);
+
+      for (const e of entries) {
+        const account = await connection.getAccountInfo(e.pda);
+        expect(account!.data[162]).to.equal(1); // status = Funded
+        const vaultAccount = await getAccount(connection, e.vault);
+        expect(Number(vaultAccount.amount)).to.equal(totalAmount / 10);
+      }
+    });
   });
 
   describe("Claim (time-dependent)", () => {
@@ -260,6 +509,7 @@ describe("pinocchio-vesting", () => {
       const [expPda, expBump] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("vesting"),
+          admin.publicKey.toBuffer(),
           beneficiary.publicKey.toBuffer(),
           mint.toBuffer(),
           expSeed.toArrayLike(Buffer, "le", 8),
@@ -286,15 +536,8 @@ describe("pinocchio-vesting", () => {
         admin,
       ]);
 
-      // Create vault ATA and deposit
+      // Deposit into the vault created with the vesting
       const expVault = await getAssociatedTokenAddress(mint, expPda, true);
-      await getOrCreateAssociatedTokenAccount(
-        connection,
-        admin,
-        mint,
-        expPda,
-        true
-      );
 
       const depIx = buildDepositIx({
         admin: admin.publicKey,
@@ -310,17 +553,12 @@ describe("pinocchio-vesting", () => {
         [admin]
       );
 
-      // Claim
+      // Claim; the beneficiary ATA does not exist yet and is created by the claim
       const beneficiaryAta = await getAssociatedTokenAddress(
         mint,
         beneficiary.publicKey
       );
-      await getOrCreateAssociatedTokenAccount(
-        connection,
-        beneficiary,
-        mint,
-        beneficiary.publicKey
-      );
+      expect(await connection.getAccountInfo(beneficiaryAta)).to.be.null;
 
       const claimIx = buildClaimIx({
         beneficiary: beneficiary.publicKey,
@@ -339,13 +577,675 @@ describe("pinocchio-vesting", () => {
       // Verify all tokens released
       const account = await connection.getAccountInfo(expPda);
       const data = account!.data;
-      const releasedAmount = Number(data.readBigUInt64LE(104));
+      const releasedAmount = Number(data.readBigUInt64LE(112));
       expect(releasedAmount).to.equal(totalAmount);
+      const beneficiaryAccount = await getAccount(connection, beneficiaryAta);
+      expect(Number(beneficiaryAccount.amount)).to.equal(totalAmount);
 
       // Vault should be empty
       const vaultAccount = await getAccount(connection, expVault);
       expect(Number(vaultAccount.amount)).to.equal(0);
     });
+
+    it("cranks a permissionless claim into the beneficiary ATA", async () => {
+      const slot = await connection.getSlot();
+      const blockTime = await connection.getBlockTime(slot);
+      const now = blockTime || Math.floor(Date.now() / 1000);
+
+      const crankSeed = new BN(61);
+      const crankAmount = 1_000 * 10 ** decimals;
+      const [crankPda, crankBump] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vesting"),
+          admin.publicKey.toBuffer(),
+          beneficiary.publicKey.toBuffer(),
+          mint.toBuffer(),
+          crankSeed.toArrayLike(Buffer, "le", 8),
+        ],
+        PINOCCHIO_PROGRAM_ID
+      );
+      const crankVault = await getAssociatedTokenAddress(mint, crankPda, true);
+
+      const createIx = buildCreateVestingIx(
+        crankSeed,
+        new BN(crankAmount),
+        new BN(now - 200),
+        new BN(now - 150),
+        new BN(now - 50),
+        crankBump,
+        {
+          admin: admin.publicKey,
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount: crankPda,
+          systemProgram: SystemProgram.programId,
+        },
+        { allowPermissionlessClaim: true }
+      );
+      const depIx = buildDepositIx({
+        admin: admin.publicKey,
+        mint,
+        vestingAccount: crankPda,
+        vault: crankVault,
+        adminTokenAccount: adminAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      });
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(createIx, depIx),
+        [admin]
+      );
+
+      // Any third party can crank, as long as tokens go to the canonical ATA
+      const cranker = Keypair.generate();
+      await airdropSol(connection, cranker.publicKey, LAMPORTS_PER_SOL);
+      const beneficiaryAta = await getAssociatedTokenAddress(
+        mint,
+        beneficiary.publicKey
+      );
+      const before = await getAccount(connection, beneficiaryAta);
+
+      const crankIx = new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: cranker.publicKey, isSigner: true, isWritable: true },
+          { pubkey: beneficiary.publicKey, isSigner: false, isWritable: false },
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: crankPda, isSigner: false, isWritable: true },
+          { pubkey: crankVault, isSigner: false, isWritable: true },
+          // Created by the program if missing, paid by the cranker
+          { pubkey: beneficiaryAta, isSigner: false, isWritable: true },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.from([8]),
+      });
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(crankIx),
+        [cranker]
+      );
+
+      const after = await getAccount(connection, beneficiaryAta);
+      expect(Number(after.amount) - Number(before.amount)).to.equal(crankAmount);
+    });
+  });
+
+  describe("Revoke and Close", () => {
+    const amount = 1_000 * 10 ** decimals;
+    let pda: PublicKey;
+    let pdaVault: PublicKey;
+    let beneficiaryAta: PublicKey;
+
+    function buildRevokeIx(signer: PublicKey, vestingAccount: PublicKey, vestingVault: PublicKey) {
+      return new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: true },
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: vestingAccount, isSigner: false, isWritable: true },
+          { pubkey: vestingVault, isSigner: false, isWritable: true },
+          { pubkey: beneficiaryAta, isSigner: false, isWritable: true },
+          { pubkey: adminAta, isSigner: false, isWritable: true },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.from([3]),
+      });
+    }
+
+    function buildCloseIx(signer: PublicKey, vestingAccount: PublicKey, vestingVault: PublicKey) {
+      return new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: true },
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: vestingAccount, isSigner: false, isWritable: true },
+          { pubkey: vestingVault, isSigner: false, isWritable: true },
+          { pubkey: adminAta, isSigner: false, isWritable: true },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.from([4]),
+      });
+    }
+
+    before(async () => {
+      const now = await clusterNow();
+      ({ pda, pdaVault } = await createFunded(
+        new BN(70),
+        amount,
+        { start: now - 100, cliff: now - 100, end: now + 100 },
+        { revocable: true }
+      ));
+      beneficiaryAta = (
+        await getOrCreateAssociatedTokenAccount(connection, admin, mint, beneficiary.publicKey)
+      ).address;
+    });
+
+    it("non-admin cannot revoke", async () => {
+      // VestingError::UnauthorizedAdmin
+      await expectCustomError(buildRevokeIx(beneficiary.publicKey, pda, pdaVault), [beneficiary], 0x1772);
+    });
+
+    it("cannot revoke a non-revocable vesting", async () => {
+      // VestingError::NotRevocable
+      await expectCustomError(buildRevokeIx(admin.publicKey, vestingPda, vault), [admin], 0x177a);
+    });
+
+    it("cannot close a vesting that is still active", async () => {
+      // VestingError::VestingNotComplete
+      await expectCustomError(buildCloseIx(admin.publicKey, pda, pdaVault), [admin], 0x177c);
+    });
+
+    it("revokes: vested to the beneficiary, the rest back to the admin", async () => {
+      const beneficiaryBefore = Number((await getAccount(connection, beneficiaryAta)).amount);
+      const adminBefore = Number((await getAccount(connection, adminAta)).amount);
+
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildRevokeIx(admin.publicKey, pda, pdaVault)),
+        [admin]
+      );
+
+      const data = (await connection.getAccountInfo(pda))!.data;
+      expect(data[162]).to.equal(2); // status = Revoked
+      expect(Number(data.readBigInt64LE(154))).to.be.greaterThan(0); // revoked_at
+      const released = Number(data.readBigUInt64LE(112));
+      expect(released).to.be.greaterThan(0).and.lessThan(amount);
+
+      const beneficiaryAfter = Number((await getAccount(connection, beneficiaryAta)).amount);
+      const adminAfter = Number((await getAccount(connection, adminAta)).amount);
+      expect(beneficiaryAfter - beneficiaryBefore).to.equal(released);
+      expect(adminAfter - adminBefore).to.equal(amount - released);
+      expect(Number((await getAccount(connection, pdaVault)).amount)).to.equal(0);
+    });
+
+    it("cannot revoke twice", async () => {
+      // VestingError::AlreadyRevoked
+      await expectCustomError(buildRevokeIx(admin.publicKey, pda, pdaVault), [admin], 0x177b);
+    });
+
+    it("non-admin cannot close", async () => {
+      // VestingError::UnauthorizedAdmin
+      await expectCustomError(buildCloseIx(beneficiary.publicKey, pda, pdaVault), [beneficiary], 0x1772);
+    });
+
+    it("closes a revoked vesting and its vault, refunding rent to the admin", async () => {
+      const rent =
+        (await connection.getBalance(pda)) + (await connection.getBalance(pdaVault));
+      const adminBefore = await connection.getBalance(admin.publicKey);
+
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildCloseIx(admin.publicKey, pda, pdaVault)),
+        [admin]
+      );
+
+      expect(await connection.getAccountInfo(pda)).to.be.null;
+      expect(await connection.getAccountInfo(pdaVault)).to.be.null;
+      // Less the transaction fee
+      const adminAfter = await connection.getBalance(admin.publicKey);
+      expect(adminAfter).to.be.greaterThan(adminBefore + rent - 10_000);
+    });
+  });
+
+  describe("Claim To", () => {
+    const amount = 1_000 * 10 ** decimals;
+    let pda: PublicKey;
+    let pdaVault: PublicKey;
+    let destination: PublicKey;
+
+    function buildClaimToIx(signer: PublicKey, amountToClaim?: number) {
+      const data = Buffer.alloc(amountToClaim === undefined ? 1 : 9);
+      data[0] = 7; // instruction index
+      if (amountToClaim !== undefined) data.writeBigUInt64LE(BigInt(amountToClaim), 1);
+      return new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: false },
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: pda, isSigner: false, isWritable: true },
+          { pubkey: pdaVault, isSigner: false, isWritable: true },
+          { pubkey: destination, isSigner: false, isWritable: true },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data,
+      });
+    }
+
+    before(async () => {
+      const now = await clusterNow();
+      ({ pda, pdaVault } = await createFunded(new BN(71), amount, {
+        start: now - 200,
+        cliff: now - 150,
+        end: now - 50,
+      }));
+      // Any token account of the mint, here one owned by a third party
+      destination = await createAccount(
+        connection,
+        admin,
+        mint,
+        Keypair.generate().publicKey,
+        Keypair.generate()
+      );
+    });
+
+    it("only the beneficiary can claim to another account", async () => {
+      // VestingError::UnauthorizedBeneficiary
+      await expectCustomError(buildClaimToIx(admin.publicKey), [admin], 0x1773);
+    });
+
+    it("rejects an amount above what is claimable", async () => {
+      // VestingError::ClaimAmountExceedsClaimable
+      await expectCustomError(buildClaimToIx(beneficiary.publicKey, amount + 1), [beneficiary], 0x1782);
+    });
+
+    it("claims a partial amount, then the rest, into the destination", async () => {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildClaimToIx(beneficiary.publicKey, amount / 4)),
+        [beneficiary]
+      );
+      expect(Number((await getAccount(connection, destination)).amount)).to.equal(amount / 4);
+
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildClaimToIx(beneficiary.publicKey)),
+        [beneficiary]
+      );
+      expect(Number((await getAccount(connection, destination)).amount)).to.equal(amount);
+
+      const data = (await connection.getAccountInfo(pda))!.data;
+      expect(Number(data.readBigUInt64LE(112))).to.equal(amount);
+      expect(data[162]).to.equal(3); // status = Completed
+    });
+  });
+
+  describe("Claim Delegate", () => {
+    const amount = 1_000 * 10 ** decimals;
+    const delegate = Keypair.generate();
+    let pda: PublicKey;
+    let pdaVault: PublicKey;
+
+    function buildSetDelegateIx(signer: PublicKey, newDelegate: PublicKey) {
+      return new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: false },
+          { pubkey: pda, isSigner: false, isWritable: true },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.concat([Buffer.from([9]), newDelegate.toBuffer()]),
+      });
+    }
+
+    function buildDelegateClaimIx(amountToClaim: number) {
+      const ix = buildClaimIx({
+        claimer: delegate.publicKey,
+        beneficiary: beneficiary.publicKey,
+        mint,
+        vestingAccount: pda,
+        vault: pdaVault,
+        beneficiaryTokenAccount: getAssociatedTokenAddressSync(mint, beneficiary.publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      });
+      ix.data = Buffer.alloc(9);
+      ix.data[0] = 2;
+      ix.data.writeBigUInt64LE(BigInt(amountToClaim), 1);
+      return ix;
+    }
+
+    before(async () => {
+      await airdropSol(connection, delegate.publicKey, LAMPORTS_PER_SOL);
+      const now = await clusterNow();
+      ({ pda, pdaVault } = await createFunded(new BN(72), amount, {
+        start: now - 200,
+        cliff: now - 150,
+        end: now - 50,
+      }));
+    });
+
+    it("only the beneficiary can set a claim delegate", async () => {
+      // VestingError::UnauthorizedBeneficiary
+      await expectCustomError(
+        buildSetDelegateIx(delegate.publicKey, delegate.publicKey),
+        [delegate],
+        0x1773
+      );
+    });
+
+    it("the delegate claims into the beneficiary's ATA", async () => {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildSetDelegateIx(beneficiary.publicKey, delegate.publicKey)),
+        [beneficiary]
+      );
+      const data = (await connection.getAccountInfo(pda))!.data;
+      expect(new PublicKey(data.subarray(312, 344)).toString()).to.equal(
+        delegate.publicKey.toString()
+      );
+
+      const beneficiaryAta = getAssociatedTokenAddressSync(mint, beneficiary.publicKey);
+      const before = Number((await getAccount(connection, beneficiaryAta)).amount);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildDelegateClaimIx(amount / 2)),
+        [delegate]
+      );
+      const after = Number((await getAccount(connection, beneficiaryAta)).amount);
+      expect(after - before).to.equal(amount / 2);
+    });
+
+    it("a cleared delegate can no longer claim", async () => {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildSetDelegateIx(beneficiary.publicKey, PublicKey.default)),
+        [beneficiary]
+      );
+      const data = (await connection.getAccountInfo(pda))!.data;
+      expect(data.subarray(312, 344).every((b) => b === 0)).to.be.true;
+
+      // VestingError::UnauthorizedBeneficiary
+      await expectCustomError(buildDelegateClaimIx(amount / 2), [delegate], 0x1773);
+    });
+  });
+
+  describe("Admin Transfer", () => {
+    const newAdmin = Keypair.generate();
+    let pda: PublicKey;
+
+    function buildProposeAdminIx(signer: PublicKey, proposed: PublicKey) {
+      return new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: false },
+          { pubkey: pda, isSigner: false, isWritable: true },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.concat([Buffer.from([10]), proposed.toBuffer()]),
+      });
+    }
+
+    function buildAcceptAdminIx(signer: PublicKey) {
+      return new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: false },
+          { pubkey: pda, isSigner: false, isWritable: true },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.from([11]),
+      });
+    }
+
+    before(async () => {
+      await airdropSol(connection, newAdmin.publicKey, LAMPORTS_PER_SOL);
+      const now = await clusterNow();
+      ({ pda } = await createFunded(new BN(73), 1_000 * 10 ** decimals, {
+        start: now - 10,
+        cliff: now + 20,
+        end: now + 60,
+      }));
+    });
+
+    it("non-admin cannot propose an admin", async () => {
+      // VestingError::UnauthorizedAdmin
+      await expectCustomError(
+        buildProposeAdminIx(beneficiary.publicKey, beneficiary.publicKey),
+        [beneficiary],
+        0x1772
+      );
+    });
+
+    it("only the proposed admin can accept", async () => {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildProposeAdminIx(admin.publicKey, newAdmin.publicKey)),
+        [admin]
+      );
+      const data = (await connection.getAccountInfo(pda))!.data;
+      expect(new PublicKey(data.subarray(344, 376)).toString()).to.equal(
+        newAdmin.publicKey.toString()
+      );
+
+      // VestingError::NotPendingAdmin
+      await expectCustomError(buildAcceptAdminIx(beneficiary.publicKey), [beneficiary], 0x1784);
+    });
+
+    it("the proposed admin accepts and the address keeps its seeds", async () => {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildAcceptAdminIx(newAdmin.publicKey)),
+        [newAdmin]
+      );
+      const data = (await connection.getAccountInfo(pda))!.data;
+      expect(new PublicKey(data.subarray(8, 40)).toString()).to.equal(
+        newAdmin.publicKey.toString()
+      );
+      expect(data.subarray(344, 376).every((b) => b === 0)).to.be.true; // pending cleared
+      // seed_admin still signs for the original address
+      expect(new PublicKey(data.subarray(376, 408)).toString()).to.equal(
+        admin.publicKey.toString()
+      );
+
+      // The previous admin lost the role
+      // VestingError::UnauthorizedAdmin
+      await expectCustomError(
+        buildProposeAdminIx(admin.publicKey, admin.publicKey),
+        [admin],
+        0x1772
+      );
+    });
+  });
+
+  describe("Beneficiary Transfer", () => {
+    const newBeneficiary = Keypair.generate().publicKey;
+
+    function buildTransferIx(vestingAccount: PublicKey, cosigner?: PublicKey) {
+      return new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: beneficiary.publicKey, isSigner: true, isWritable: false },
+          { pubkey: vestingAccount, isSigner: false, isWritable: true },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+          ...(cosigner ? [{ pubkey: cosigner, isSigner: true, isWritable: false }] : []),
+        ],
+        data: Buffer.concat([Buffer.from([12]), newBeneficiary.toBuffer()]),
+      });
+    }
+
+    async function createGrant(vestingSeed: number, transferable: boolean) {
+      const now = await clusterNow();
+      const { pda } = await createFunded(
+        new BN(vestingSeed),
+        1_000 * 10 ** decimals,
+        { start: now - 10, cliff: now + 20, end: now + 60 },
+        { transferable }
+      );
+      return pda;
+    }
+
+    it("a non-transferable vesting needs the admin's co-signature", async () => {
+      const pda = await createGrant(74, false);
+
+      // VestingError::NotTransferable
+      await expectCustomError(buildTransferIx(pda), [beneficiary], 0x1785);
+
+      // A co-signer that is not the admin does not count
+      const stranger = Keypair.generate();
+      await airdropSol(connection, stranger.publicKey, LAMPORTS_PER_SOL);
+      // VestingError::UnauthorizedAdmin
+      await expectCustomError(buildTransferIx(pda, stranger.publicKey), [beneficiary, stranger], 0x1772);
+
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildTransferIx(pda, admin.publicKey)),
+        [beneficiary, admin]
+      );
+      const data = (await connection.getAccountInfo(pda))!.data;
+      expect(new PublicKey(data.subarray(40, 72)).toString()).to.equal(newBeneficiary.toString());
+      // seed_beneficiary still signs for the original address
+      expect(new PublicKey(data.subarray(409, 441)).toString()).to.equal(
+        beneficiary.publicKey.toString()
+      );
+
+      // The previous beneficiary lost the position
+      // VestingError::UnauthorizedBeneficiary
+      await expectCustomError(buildTransferIx(pda, admin.publicKey), [beneficiary, admin], 0x1773);
+    });
+
+    it("a transferable vesting moves on the beneficiary's signature alone", async () => {
+      const pda = await createGrant(75, true);
+
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildTransferIx(pda)),
+        [beneficiary]
+      );
+      const data = (await connection.getAccountInfo(pda))!.data;
+      expect(new PublicKey(data.subarray(40, 72)).toString()).to.equal(newBeneficiary.toString());
+    });
+  });
+
+  describe("Migrate", () => {
+    function buildMigrateIx(vestingAccount: PublicKey, vestingVault: PublicKey) {
+      return new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: admin.publicKey, isSigner: true, isWritable: true },
+          { pubkey: vestingAccount, isSigner: false, isWritable: true },
+          { pubkey: vestingVault, isSigner: false, isWritable: true },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.from([5]),
+      });
+    }
+
+    it("migrates a legacy account in place", async () => {
+      expect((await connection.getAccountInfo(LEGACY_VESTING))!.data.length).to.equal(145);
+
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildMigrateIx(LEGACY_VESTING, LEGACY_VESTING_VAULT)),
+        [admin]
+      );
+
+      const data = (await connection.getAccountInfo(LEGACY_VESTING))!.data;
+      expect(data.length).to.equal(474);
+      expect(Array.from(data.subarray(0, 8))).to.deep.equal([102, 73, 10, 233, 200, 188, 228, 216]);
+      expect(data[473]).to.equal(1); // layout_version
+      expect(new PublicKey(data.subarray(8, 40)).toString()).to.equal(LEGACY_ADMIN.toString());
+      expect(Number(data.readBigUInt64LE(104))).to.equal(1_000_000);
+      // The vault covers everything outstanding
+      expect(data[162]).to.equal(1); // status = Funded
+      expect(data[163]).to.equal(1); // legacy_seeds
+      expect(new PublicKey(data.subarray(376, 408)).toString()).to.equal(LEGACY_ADMIN.toString());
+      expect(new PublicKey(data.subarray(409, 441)).toString()).to.equal(
+        LEGACY_BENEFICIARY.toString()
+      );
+    });
+
+    it("migrates a headerless account by adding the header", async () => {
+      const before = (await connection.getAccountInfo(HEADERLESS_VESTING))!.data;
+      expect(before.length).to.equal(465);
+
+      // The vault is only read for legacy accounts
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildMigrateIx(HEADERLESS_VESTING, Keypair.generate().publicKey)),
+        [admin]
+      );
+
+      const data = (await connection.getAccountInfo(HEADERLESS_VESTING))!.data;
+      expect(data.length).to.equal(474);
+      expect(Array.from(data.subarray(0, 8))).to.deep.equal([102, 73, 10, 233, 200, 188, 228, 216]);
+      expect(data[473]).to.equal(1); // layout_version
+      // Every field is kept as stored, just behind the discriminator
+      expect(data.subarray(8, 473).equals(before)).to.be.true;
+    });
+
+    it("rejects accounts already in the current layout", async () => {
+      // VestingError::InvalidLegacyAccount
+      await expectCustomError(buildMigrateIx(LEGACY_VESTING, LEGACY_VESTING_VAULT), [admin], 0x177e);
+      await expectCustomError(buildMigrateIx(vestingPda, vault), [admin], 0x177e);
+    });
+  });
+
+  describe("Update Config", () => {
+    const [programData] = PublicKey.findProgramAddressSync(
+      [PINOCCHIO_PROGRAM_ID.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_ID
+    );
+
+    function buildUpdateConfigIx(
+      authority: PublicKey,
+      pauseFlags: number,
+      depositFeeBps = 0
+    ): TransactionInstruction {
+      const feeSchedule = Buffer.alloc(32 + 8 + 2 + 1);
+      provider.publicKey.toBuffer().copy(feeSchedule, 0); // treasury
+      feeSchedule.writeUInt16LE(depositFeeBps, 40);
+      return new TransactionInstruction({
+        programId: PINOCCHIO_PROGRAM_ID,
+        keys: [
+          { pubkey: authority, isSigner: true, isWritable: false },
+          { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: true },
+          { pubkey: programData, isSigner: false, isWritable: false },
+        ],
+        // paused = 0, pause_flags, no creation fee, no exemptions
+        data: Buffer.concat([Buffer.from([14, 0, pauseFlags]), feeSchedule]),
+      });
+    }
+
+    it("only the upgrade authority can update the config", async () => {
+      // VestingError::UnauthorizedConfigAuthority
+      await expectCustomError(buildUpdateConfigIx(admin.publicKey, 0), [admin], 0x1787);
+    });
+
+    it("rejects a deposit fee above 100%", async () => {
+      try {
+        await provider.sendAndConfirm(
+          new Transaction().add(buildUpdateConfigIx(provider.publicKey, 0, 10_001))
+        );
+        expect.fail("Should have failed: fee above 100%");
+      } catch (err: any) {
+        // VestingError::InvalidFeeConfig
+        expect(err.toString()).to.include("custom program error: 0x1788");
+      }
+    });
+
+    it("pausing claims blocks claim until unpaused", async () => {
+      const PAUSE_CLAIM = 1 << 2;
+      await provider.sendAndConfirm(
+        new Transaction().add(buildUpdateConfigIx(provider.publicKey, PAUSE_CLAIM))
+      );
+      try {
+        const config = (await connection.getAccountInfo(PINOCCHIO_CONFIG))!.data;
+        expect(config[1]).to.equal(PAUSE_CLAIM);
+
+        const claimIx = buildClaimIx({
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount: vestingPda,
+          vault,
+          beneficiaryTokenAccount: getAssociatedTokenAddressSync(mint, beneficiary.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        });
+        // VestingError::ProgramPaused
+        await expectCustomError(claimIx, [beneficiary], 0x1786);
+      } finally {
+        await provider.sendAndConfirm(
+          new Transaction().add(buildUpdateConfigIx(provider.publicKey, 0))
+        );
+      }
+      const config = (await connection.getAccountInfo(PINOCCHIO_CONFIG))!.data;
+      expect(config[1]).to.equal(0);
+    });
   });
 
   describe("Security", () => {
@@ -359,6 +1259,7 @@ describe("pinocchio-vesting", () => {
       const [uaPda, uaBump] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("vesting"),
+          admin.publicKey.toBuffer(),
           beneficiary.publicKey.toBuffer(),
           mint.toBuffer(),
           uaSeed.toArrayLike(Buffer, "le", 8),
@@ -388,13 +1289,6 @@ describe("pinocchio-vesting", () => {
       );
 
       const uaVault = await getAssociatedTokenAddress(mint, uaPda, true);
-      await getOrCreateAssociatedTokenAccount(
-        connection,
-        admin,
-        mint,
-        uaPda,
-        true
-      );
 
       // Beneficiary tries to deposit (not admin)
       const beneficiaryAtaAcc = await getOrCreateAssociatedTokenAccount(
@@ -421,7 +1315,8 @@ describe("pinocchio-vesting", () => {
         );
         expect.fail("Should have failed: unauthorized admin");
       } catch (err: any) {
-        expect(err.toString()).to.include("Error");
+        // VestingError::UnauthorizedAdmin, same code as the Anchor program
+        expect(err.toString()).to.include("custom program error: 0x1772");
       }
     });
 
@@ -444,20 +1339,81 @@ describe("pinocchio-vesting", () => {
         );
         expect.fail("Should have failed: already funded");
       } catch (err: any) {
-        expect(err.toString()).to.include("Error");
+        // VestingError::AlreadyFunded, same code as the Anchor program
+        expect(err.toString()).to.include("custom program error: 0x1774");
       }
     });
 
-    it("claim fails before cliff (nothing to claim)", async () => {
-      const beneficiaryAta = await getAssociatedTokenAddress(
-        mint,
-        beneficiary.publicKey
+    it("deposit rejects a vault that is not the vesting's ATA", async () => {
+      const slot = await connection.getSlot();
+      const blockTime = await connection.getBlockTime(slot);
+      const now = blockTime || Math.floor(Date.now() / 1000);
+
+      const vaSeed = new BN(21);
+      const [vaPda, vaBump] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vesting"),
+          admin.publicKey.toBuffer(),
+          beneficiary.publicKey.toBuffer(),
+          mint.toBuffer(),
+          vaSeed.toArrayLike(Buffer, "le", 8),
+        ],
+        PINOCCHIO_PROGRAM_ID
+      );
+
+      const createIx = buildCreateVestingIx(
+        vaSeed,
+        new BN(totalAmount),
+        new BN(now - 10),
+        new BN(now + 20),
+        new BN(now + 60),
+        vaBump,
+        {
+          admin: admin.publicKey,
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount: vaPda,
+          systemProgram: SystemProgram.programId,
+        }
+      );
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(createIx),
+        [admin]
       );
 
-      // Create beneficiary ATA
-      await getOrCreateAssociatedTokenAccount(
+      // Owned by the vesting PDA and of the right mint, but not its ATA
+      const strayVault = await createAccount(
         connection,
-        beneficiary,
+        admin,
+        mint,
+        vaPda,
+        Keypair.generate()
+      );
+
+      const depIx = buildDepositIx({
+        admin: admin.publicKey,
+        mint,
+        vestingAccount: vaPda,
+        vault: strayVault,
+        adminTokenAccount: adminAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      });
+
+      try {
+        await sendAndConfirmTransaction(
+          connection,
+          new Transaction().add(depIx),
+          [admin]
+        );
+        expect.fail("Should have failed: vault is not the vesting's ATA");
+      } catch (err: any) {
+        expect(err.toString()).to.include("Error");
+      }
+    });
+
+    it("claim fails before cliff (nothing to claim)", async () => {
+      const beneficiaryAta = await getAssociatedTokenAddress(
         mint,
         beneficiary.publicKey
       );
@@ -476,7 +1432,28 @@ describe("pinocchio-vesting", () => {
         await sendAndConfirmTransaction(connection, tx, [beneficiary]);
         expect.fail("Should have failed: cliff not reached");
       } catch (err: any) {
-        expect(err.toString()).to.include("Error");
+        // VestingError::NothingToClaim, same code as the Anchor program
+        expect(err.toString()).to.include("custom program error: 0x1776");
+      }
+    });
+
+    it("claim rejects an amount that is not exactly 8 bytes", async () => {
+      const ix = buildClaimIx({
+        beneficiary: beneficiary.publicKey,
+        mint,
+        vestingAccount: vestingPda,
+        vault,
+        beneficiaryTokenAccount: await getAssociatedTokenAddress(mint, beneficiary.publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      });
+      // Instruction index + a truncated 4-byte amount
+      ix.data = Buffer.from([2, 1, 0, 0, 0]);
+
+      try {
+        await sendAndConfirmTransaction(connection, new Transaction().add(ix), [beneficiary]);
+        expect.fail("Should have failed: truncated amount");
+      } catch (err: any) {
+        expect(err.toString()).to.include("invalid instruction data");
       }
     });
   });
@@ -487,6 +1464,7 @@ describe("pinocchio-vesting", () => {
       const [badPda, badBump] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("vesting"),
+          admin.publicKey.toBuffer(),
           beneficiary.publicKey.toBuffer(),
           mint.toBuffer(),
           badSeed.toArrayLike(Buffer, "le", 8),
@@ -515,7 +1493,8 @@ describe("pinocchio-vesting", () => {
         await sendAndConfirmTransaction(connection, tx, [admin]);
         expect.fail("Should have failed: zero amount");
       } catch (err: any) {
-        expect(err.toString()).to.include("Error");
+        // VestingError::InvalidAmount, same code as the Anchor program
+        expect(err.toString()).to.include("custom program error: 0x1771");
       }
     });
 
@@ -524,6 +1503,7 @@ describe("pinocchio-vesting", () => {
       const [badPda, badBump] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("vesting"),
+          admin.publicKey.toBuffer(),
           beneficiary.publicKey.toBuffer(),
           mint.toBuffer(),
           badSeed.toArrayLike(Buffer, "le", 8),
@@ -552,7 +1532,8 @@ describe("pinocchio-vesting", () => {
         await sendAndConfirmTransaction(connection, tx, [admin]);
         expect.fail("Should have failed: invalid time range");
       } catch (err: any) {
-        expect(err.toString()).to.include("Error");
+        // VestingError::InvalidTimeRange, same code as the Anchor program
+        expect(err.toString()).to.include("custom program error: 0x1770");
       }
     });
   });
@@ -567,3 +1548,33 @@ async function airdropSol(
   const latestBlockhash = await connection.getLatestBlockhash();
   await connection.confirmTransaction({ signature: sig, ...latestBlockhash });
 }
+
+/** Create the Pinocchio program config (unpaused, no fees) unless an earlier suite did */
+async function ensurePinocchioConfig(provider: anchor.AnchorProvider) {
+  if (await provider.connection.getAccountInfo(PINOCCHIO_CONFIG)) return;
+
+  const [, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    PINOCCHIO_PROGRAM_ID
+  );
+  const [programData] = PublicKey.findProgramAddressSync(
+    [PINOCCHIO_PROGRAM_ID.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+  const ix = new TransactionInstruction({
+    programId: PINOCCHIO_PROGRAM_ID,
+    keys: [
+      { pubkey: provider.publicKey, isSigner: true, isWritable: true },
+      { pubkey: PINOCCHIO_CONFIG, isSigner: false, isWritable: true },
+      { pubkey: programData, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    // initialize_config, unpaused, treasury = provider wallet, no fees, no exemptions
+    data: Buffer.concat([
+      Buffer.from([13, 0, 0, bump]),
+      provider.publicKey.toBuffer(),
+      Buffer.alloc(8 + 2 + 1),
+    ]),
+  });
+  await provider.sendAndConfirm(new Transaction().add(ix));
+}
@@ -36,6 +36,10 @@ const PINOCCHIO_PROGRAM_ID = new PublicKey(
   "EzRUZpW3CsvnKzEUiF7fAPHyHWsv2D3ERR482BPKHwYk"
 );
 
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
 describe("Security Test Suite", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
@@ -66,6 +70,8 @@ describe("Security Test Suite", () => {
   let baseEnd: number;
 
   before(async () => {
+    await ensureAnchorConfig(program);
+
     const airdropAmount = 20 * LAMPORTS_PER_SOL;
     await Promise.all([
       airdropSol(connection, admin.publicKey, airdropAmount),
@@ -119,6 +125,7 @@ describe("Security Test Suite", () => {
     [basePda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("vesting"),
+        admin.publicKey.toBuffer(),
         beneficiary.publicKey.toBuffer(),
         mint.toBuffer(),
         baseSeed.toArrayLike(Buffer, "le", 8),
@@ -128,13 +135,21 @@ describe("Security Test Suite", () => {
     baseVault = await getAssociatedTokenAddress(mint, basePda, true);
 
     await program.methods
-      .createVesting(
-        baseSeed,
-        new BN(totalAmount),
-        new BN(baseStart),
-        new BN(baseCliff),
-        new BN(baseEnd)
-      )
+      .createVesting({
+        seed: baseSeed,
+        totalAmount: new BN(totalAmount),
+        startTime: new BN(baseStart),
+        cliffTime: new BN(baseCliff),
+        endTime: new BN(baseEnd),
+        revocable: false,
+        scheduleKind: { linear: {} },
+        periodSeconds: new BN(0),
+        schedulePoints: [],
+        cliffAmount: new BN(0),
+        accrueFromCliff: false,
+        allowPermissionlessClaim: false,
+        transferable: false,
+      })
       .accountsPartial({
         admin: admin.publicKey,
         beneficiary: beneficiary.publicKey,
@@ -207,6 +222,7 @@ describe("Security Test Suite", () => {
       const [aPda] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("vesting"),
+          admin.publicKey.toBuffer(),
           beneficiary.publicKey.toBuffer(),
           mint.toBuffer(),
           aSeed.toArrayLike(Buffer, "le", 8),
@@ -220,13 +236,21 @@ describe("Security Test Suite", () => {
       const now = blockTime || Math.floor(Date.now() / 1000);
 
       await program.methods
-        .createVesting(
-          aSeed,
-          new BN(totalAmount),
-          new BN(now - 10),
-          new BN(now + 20),
-          new BN(now + 60)
-        )
+        .createVesting({
+          seed: aSeed,
+          totalAmount: new BN(totalAmount),
+          startTime: new BN(now - 10),
+          cliffTime: new BN(now + 20),
+          endTime: new BN(now + 60),
+          revocable: false,
+          scheduleKind: { linear: {} },
+          periodSeconds: new BN(0),
+          schedulePoints: [],
+          cliffAmount: new BN(0),
+          accrueFromCliff: false,
+          allowPermissionlessClaim: false,
+          transferable: false,
+        })
         .accountsPartial({
           admin: admin.publicKey,
           beneficiary: beneficiary.publicKey,
@@ -285,8 +309,9 @@ describe("Security Test Suite", () => {
 
       try {
         await program.methods
-          .claim()
+          .claim(null)
           .accountsPartial({
+            claimer: attacker.publicKey,
             beneficiary: attacker.publicKey, // Wrong beneficiary
             mint,
             vestingAccount: basePda,
@@ -312,8 +337,9 @@ describe("Security Test Suite", () => {
 
       try {
         await program.methods
-          .claim()
+          .claim(null)
           .accountsPartial({
+            claimer: admin.publicKey,
             beneficiary: admin.publicKey, // Admin trying to claim
             mint,
             vestingAccount: basePda,
@@ -344,8 +370,9 @@ describe("Security Test Suite", () => {
       );
 
       await program.methods
-        .claim()
+        .claim(null)
         .accountsPartial({
+          claimer: beneficiary.publicKey,
           beneficiary: beneficiary.publicKey,
           mint,
           vestingAccount: basePda,
@@ -414,8 +441,9 @@ describe("Security Test Suite", () => {
 
       try {
         await program.methods
-          .claim()
+          .claim(null)
           .accountsPartial({
+            claimer: beneficiary.publicKey,
             beneficiary: beneficiary.publicKey,
             mint,
             vestingAccount: basePda,
@@ -479,6 +507,7 @@ describe("Security Test Suite", () => {
       const [derivedPda, derivedBump] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("vesting"),
+          vesting.admin.toBuffer(),
           vesting.beneficiary.toBuffer(),
           vesting.mint.toBuffer(),
           vesting.seed.toArrayLike(Buffer, "le", 8),
@@ -500,6 +529,7 @@ describe("Security Test Suite", () => {
       const [fakePda] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("vesting"),
+          admin.publicKey.toBuffer(),
           beneficiary.publicKey.toBuffer(),
           mint.toBuffer(),
           fakeSeed.toArrayLike(Buffer, "le", 8),
@@ -515,13 +545,21 @@ describe("Security Test Suite", () => {
       try {
         // Pass seed=998 but account is PDA for seed=999 → seeds won't match
         await program.methods
-          .createVesting(
-            new BN(998), // Wrong seed
-            new BN(totalAmount),
-            new BN(now - 10),
-            new BN(now + 20),
-            new BN(now + 60)
-          )
+          .createVesting({
+            seed: new BN(998), // Wrong seed
+            totalAmount: new BN(totalAmount),
+            startTime: new BN(now - 10),
+            cliffTime: new BN(now + 20),
+            endTime: new BN(now + 60),
+            revocable: false,
+            scheduleKind: { linear: {} },
+            periodSeconds: new BN(0),
+            schedulePoints: [],
+            cliffAmount: new BN(0),
+            accrueFromCliff: false,
+            allowPermissionlessClaim: false,
+            transferable: false,
+          })
           .accountsPartial({
             admin: fakeAdmin.publicKey,
             beneficiary: beneficiary.publicKey,
@@ -539,6 +577,68 @@ describe("Security Test Suite", () => {
         expect(err.toString()).to.include("Error");
       }
     });
+
+    it("SEC-4.3: attacker cannot squat another admin's vesting address", async () => {
+      // Attacker front-runs with the same beneficiary/mint/seed
+      const squatSeed = new BN(910);
+      const slot = await connection.getSlot();
+      const blockTime = await connection.getBlockTime(slot);
+      const now = blockTime || Math.floor(Date.now() / 1000);
+
+      const pdaFor = (authority: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vesting"),
+            authority.toBuffer(),
+            beneficiary.publicKey.toBuffer(),
+            mint.toBuffer(),
+            squatSeed.toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        )[0];
+      const attackerPda = pdaFor(attacker.publicKey);
+      const adminPda = pdaFor(admin.publicKey);
+      expect(attackerPda.toString()).to.not.equal(adminPda.toString());
+
+      const create = (signer: Keypair, pda: PublicKey) =>
+        getAssociatedTokenAddress(mint, pda, true).then((vault) =>
+          program.methods
+            .createVesting({
+              seed: squatSeed,
+              totalAmount: new BN(1),
+              startTime: new BN(now - 10),
+              cliffTime: new BN(now + 20),
+              endTime: new BN(now + 60),
+              revocable: false,
+              scheduleKind: { linear: {} },
+              periodSeconds: new BN(0),
+              schedulePoints: [],
+              cliffAmount: new BN(0),
+              accrueFromCliff: false,
+              allowPermissionlessClaim: false,
+              transferable: false,
+            })
+            .accountsPartial({
+              admin: signer.publicKey,
+              beneficiary: beneficiary.publicKey,
+              mint,
+              vestingAccount: pda,
+              vault,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            })
+            .signers([signer])
+            .rpc()
+        );
+
+      await create(attacker, attackerPda);
+
+      // The legitimate admin's create still succeeds at its own address
+      await create(admin, adminPda);
+      const vesting = await program.account.vestingAccount.fetch(adminPda);
+      expect(vesting.admin.toString()).to.equal(admin.publicKey.toString());
+    });
   });
 
   // ─────────────────────────────────────────
@@ -550,13 +650,21 @@ describe("Security Test Suite", () => {
       // basePda already exists; try to create again with same seeds
       try {
         await program.methods
-          .createVesting(
-            baseSeed,
-            new BN(totalAmount),
-            new BN(baseStart),
-            new BN(baseCliff),
-            new BN(baseEnd)
-          )
+          .createVesting({
+            seed: baseSeed,
+            totalAmount: new BN(totalAmount),
+            startTime: new BN(baseStart),
+            cliffTime: new BN(baseCliff),
+            endTime: new BN(baseEnd),
+            revocable: false,
+            scheduleKind: { linear: {} },
+            periodSeconds: new BN(0),
+            schedulePoints: [],
+            cliffAmount: new BN(0),
+            accrueFromCliff: false,
+            allowPermissionlessClaim: false,
+            transferable: false,
+          })
           .accountsPartial({
             admin: admin.publicKey,
             beneficiary: beneficiary.publicKey,
@@ -614,6 +722,7 @@ describe("Security Test Suite", () => {
       const [bigPda] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("vesting"),
+          admin.publicKey.toBuffer(),
           beneficiary.publicKey.toBuffer(),
           mint.toBuffer(),
           bigSeed.toArrayLike(Buffer, "le", 8),
@@ -624,13 +733,21 @@ describe("Security Test Suite", () => {
 
       // Should succeed (u128 intermediate math handles this)
       await program.methods
-        .createVesting(
-          bigSeed,
-          bigAmount,
-          new BN(now - 100),
-          new BN(now - 50),
-          new BN(now + 100)
-        )
+        .createVesting({
+          seed: bigSeed,
+          totalAmount: bigAmount,
+          startTime: new BN(now - 100),
+          cliffTime: new BN(now - 50),
+          endTime: new BN(now + 100),
+          revocable: false,
+          scheduleKind: { linear: {} },
+          periodSeconds: new BN(0),
+          schedulePoints: [],
+          cliffAmount: new BN(0),
+          accrueFromCliff: false,
+          allowPermissionlessClaim: false,
+          transferable: false,
+        })
         .accountsPartial({
           admin: admin.publicKey,
           beneficiary: beneficiary.publicKey,
@@ -703,3 +820,27 @@ async function airdropSol(
   const latestBlockhash = await connection.getLatestBlockhash();
   await connection.confirmTransaction({ signature: sig, ...latestBlockhash });
 }
+
+/** Create the Anchor program config (unpaused, no fees) unless an earlier suite did */
+async function ensureAnchorConfig(program: Program<AnchorVesting>) {
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  if (await program.provider.connection.getAccountInfo(config)) return;
+
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+  await program.methods
+    .initializeConfig(false, 0, program.provider.publicKey, new BN(0), 0, [])
+    .accountsPartial({
+      authority: program.provider.publicKey,
+      config,
+      program: program.programId,
+      programData,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}